@@ -0,0 +1,432 @@
+use crate::{
+    dependencies::BackstopClient,
+    errors::ShareTokenError,
+    events::ShareTokenEvents,
+    storage::{self, AllowanceValue},
+};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, String};
+
+/// The decimals of the backstop share token, matching the backstop's share accounting
+const DECIMALS: u32 = 7;
+
+#[contract]
+pub struct BackstopShareTokenContract;
+
+#[contractclient(name = "BackstopShareTokenClient")]
+pub trait BackstopShareToken {
+    /********** SEP-41 **********/
+
+    /// Fetch the allowance "spender" has over the shares of "from"
+    ///
+    /// ### Arguments
+    /// * `from` - The owner of the shares
+    /// * `spender` - The address allowed to spend the shares
+    fn allowance(e: Env, from: Address, spender: Address) -> i128;
+
+    /// Set the allowance "spender" has over the shares of "from"
+    ///
+    /// ### Arguments
+    /// * `from` - The owner of the shares
+    /// * `spender` - The address allowed to spend the shares
+    /// * `amount` - The amount of shares allowed to be spent
+    /// * `expiration_ledger` - The ledger the allowance expires at
+    ///
+    /// ### Errors
+    /// If the amount is negative, or if the expiration ledger has passed for a non-zero amount
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32);
+
+    /// Fetch the un-queued backstop shares "id" owns
+    ///
+    /// ### Arguments
+    /// * `id` - The address to fetch the balance for
+    fn balance(e: Env, id: Address) -> i128;
+
+    /// Transfer un-queued backstop shares from "from" to "to". Backstop emissions are
+    /// settled for both addresses by the backstop before the shares move.
+    ///
+    /// ### Arguments
+    /// * `from` - The address sending the shares
+    /// * `to` - The address receiving the shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the amount is negative or "from" does not have enough un-queued shares
+    fn transfer(e: Env, from: Address, to: Address, amount: i128);
+
+    /// Transfer un-queued backstop shares from "from" to "to" using the allowance of "spender"
+    ///
+    /// ### Arguments
+    /// * `spender` - The address spending the shares
+    /// * `from` - The address sending the shares
+    /// * `to` - The address receiving the shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the amount is negative, the allowance is too low, or "from" does not have enough
+    /// un-queued shares
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128);
+
+    /// Not supported. Backstop shares are burned by queueing them for withdrawal in the backstop.
+    fn burn(e: Env, from: Address, amount: i128);
+
+    /// Not supported. Backstop shares are burned by queueing them for withdrawal in the backstop.
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128);
+
+    /// Fetch the decimals of the token
+    fn decimals(e: Env) -> u32;
+
+    /// Fetch the name of the token
+    fn name(e: Env) -> String;
+
+    /// Fetch the symbol of the token
+    fn symbol(e: Env) -> String;
+
+    /********** Backstop **********/
+
+    /// Fetch the backstop the token wraps shares of
+    fn backstop(e: Env) -> Address;
+
+    /// Fetch the pool whose backstop shares the token wraps
+    fn pool(e: Env) -> Address;
+
+    /// (Only Backstop) Emit a mint event for shares credited to "to" by the backstop
+    ///
+    /// ### Arguments
+    /// * `to` - The address receiving the shares
+    /// * `amount` - The amount of shares minted
+    fn backstop_mint(e: Env, to: Address, amount: i128);
+
+    /// (Only Backstop) Emit a burn event for shares removed from "from" by the backstop
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares were removed
+    /// * `amount` - The amount of shares burned
+    fn backstop_burn(e: Env, from: Address, amount: i128);
+
+    /// (Only Backstop) Emit a transfer event for shares moved by the backstop
+    ///
+    /// ### Arguments
+    /// * `from` - The address sending the shares
+    /// * `to` - The address receiving the shares
+    /// * `amount` - The amount of shares transferred
+    fn backstop_transfer(e: Env, from: Address, to: Address, amount: i128);
+}
+
+#[contractimpl]
+impl BackstopShareTokenContract {
+    /// Construct the backstop share token contract
+    ///
+    /// ### Arguments
+    /// * `backstop` - The backstop contract whose shares are wrapped
+    /// * `pool` - The pool whose backstop shares are wrapped
+    pub fn __constructor(e: Env, backstop: Address, pool: Address) {
+        storage::set_backstop(&e, &backstop);
+        storage::set_pool(&e, &pool);
+    }
+}
+
+#[contractimpl]
+impl BackstopShareToken for BackstopShareTokenContract {
+    /********** SEP-41 **********/
+
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        storage::get_allowance(&e, &from, &spender).amount
+    }
+
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        require_nonnegative(&e, amount);
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            panic_with_error!(&e, ShareTokenError::InvalidExpirationLedger);
+        }
+        storage::set_allowance(
+            &e,
+            &from,
+            &spender,
+            &AllowanceValue {
+                amount,
+                expiration_ledger,
+            },
+        );
+
+        ShareTokenEvents::approve(&e, from, spender, amount, expiration_ledger);
+    }
+
+    fn balance(e: Env, id: Address) -> i128 {
+        let backstop_client = BackstopClient::new(&e, &storage::get_backstop(&e));
+        backstop_client
+            .user_balance(&storage::get_pool(&e), &id)
+            .shares
+    }
+
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        execute_transfer(&e, &from, &to, amount);
+
+        ShareTokenEvents::transfer(&e, from, to, amount);
+    }
+
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+
+        require_nonnegative(&e, amount);
+        let mut allowance = storage::get_allowance(&e, &from, &spender);
+        if allowance.amount < amount {
+            panic_with_error!(&e, ShareTokenError::AllowanceError);
+        }
+        if amount > 0 {
+            allowance.amount -= amount;
+            storage::set_allowance(&e, &from, &spender, &allowance);
+        }
+        execute_transfer(&e, &from, &to, amount);
+
+        ShareTokenEvents::transfer(&e, from, to, amount);
+    }
+
+    fn burn(e: Env, _from: Address, _amount: i128) {
+        panic_with_error!(&e, ShareTokenError::NotSupported);
+    }
+
+    fn burn_from(e: Env, _spender: Address, _from: Address, _amount: i128) {
+        panic_with_error!(&e, ShareTokenError::NotSupported);
+    }
+
+    fn decimals(_e: Env) -> u32 {
+        DECIMALS
+    }
+
+    fn name(e: Env) -> String {
+        String::from_str(&e, "Blend Backstop Share")
+    }
+
+    fn symbol(e: Env) -> String {
+        String::from_str(&e, "BBS")
+    }
+
+    /********** Backstop **********/
+
+    fn backstop(e: Env) -> Address {
+        storage::get_backstop(&e)
+    }
+
+    fn pool(e: Env) -> Address {
+        storage::get_pool(&e)
+    }
+
+    fn backstop_mint(e: Env, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        let backstop = storage::get_backstop(&e);
+        backstop.require_auth();
+
+        ShareTokenEvents::mint(&e, backstop, to, amount);
+    }
+
+    fn backstop_burn(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        storage::get_backstop(&e).require_auth();
+
+        ShareTokenEvents::burn(&e, from, amount);
+    }
+
+    fn backstop_transfer(e: Env, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        storage::get_backstop(&e).require_auth();
+
+        ShareTokenEvents::transfer(&e, from, to, amount);
+    }
+}
+
+/// Require that an incoming amount is not negative
+///
+/// ### Arguments
+/// * `amount` - The amount
+///
+/// ### Errors
+/// If the number is negative
+fn require_nonnegative(e: &Env, amount: i128) {
+    if amount.is_negative() {
+        panic_with_error!(e, ShareTokenError::NegativeAmountError);
+    }
+}
+
+/// Move "amount" of un-queued shares from "from" to "to" through the backstop. Transfers
+/// to self and of zero shares do not modify any balances.
+fn execute_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    require_nonnegative(e, amount);
+    if from == to || amount == 0 {
+        return;
+    }
+    let backstop_client = BackstopClient::new(e, &storage::get_backstop(e));
+    backstop_client.share_token_transfer(&storage::get_pool(e), from, to, &amount);
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::testutils::Address as _;
+
+    use crate::testutils::{create_share_token, set_ledger};
+
+    use super::*;
+
+    /********** approve **********/
+
+    #[test]
+    fn test_approve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, _) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &200);
+        assert_eq!(share_token_client.allowance(&samwise, &frodo), 10_0000000);
+        assert_eq!(share_token_client.allowance(&frodo, &samwise), 0);
+
+        // a zero allowance can be set with a past expiration ledger
+        share_token_client.approve(&samwise, &frodo, &0, &0);
+        assert_eq!(share_token_client.allowance(&samwise, &frodo), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #8)")]
+    fn test_approve_negative_amount() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, _) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        share_token_client.approve(&samwise, &frodo, &-1, &200);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1400)")]
+    fn test_approve_expired_ledger() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, _) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &99);
+    }
+
+    #[test]
+    fn test_allowance_expires() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, _) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &200);
+
+        set_ledger(&e, 200);
+        assert_eq!(share_token_client.allowance(&samwise, &frodo), 10_0000000);
+
+        set_ledger(&e, 201);
+        assert_eq!(share_token_client.allowance(&samwise, &frodo), 0);
+    }
+
+    /********** transfer_from **********/
+
+    #[test]
+    fn test_transfer_from() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, backstop_client) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        backstop_client.set_shares(&samwise, &50_0000000);
+
+        share_token_client.approve(&samwise, &frodo, &30_0000000, &200);
+        share_token_client.transfer_from(&frodo, &samwise, &merry, &20_0000000);
+
+        assert_eq!(share_token_client.allowance(&samwise, &frodo), 10_0000000);
+        assert_eq!(share_token_client.balance(&samwise), 30_0000000);
+        assert_eq!(share_token_client.balance(&frodo), 0);
+        assert_eq!(share_token_client.balance(&merry), 20_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #9)")]
+    fn test_transfer_from_over_allowance() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, backstop_client) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        backstop_client.set_shares(&samwise, &50_0000000);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &200);
+        share_token_client.transfer_from(&frodo, &samwise, &frodo, &10_0000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #9)")]
+    fn test_transfer_from_expired_allowance() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, backstop_client) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        backstop_client.set_shares(&samwise, &50_0000000);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &200);
+
+        set_ledger(&e, 201);
+        share_token_client.transfer_from(&frodo, &samwise, &frodo, &1_0000000);
+    }
+
+    /********** burn **********/
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1401)")]
+    fn test_burn_not_supported() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, backstop_client) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        backstop_client.set_shares(&samwise, &50_0000000);
+
+        share_token_client.burn(&samwise, &1_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1401)")]
+    fn test_burn_from_not_supported() {
+        let e = Env::default();
+        e.mock_all_auths();
+        set_ledger(&e, 100);
+
+        let (share_token_client, backstop_client) = create_share_token(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        backstop_client.set_shares(&samwise, &50_0000000);
+
+        share_token_client.approve(&samwise, &frodo, &10_0000000, &200);
+        share_token_client.burn_from(&frodo, &samwise, &1_0000000);
+    }
+}
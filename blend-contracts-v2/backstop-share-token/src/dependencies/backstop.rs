@@ -0,0 +1,32 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// A deposit that is queued for withdrawal
+///
+/// Mirrors the `Q4W` type of the backstop contract
+#[derive(Clone)]
+#[contracttype]
+pub struct Q4W {
+    pub amount: i128,
+    pub exp: u64,
+}
+
+/// A user's backstop balance for a pool
+///
+/// Mirrors the `UserBalance` type of the backstop contract
+#[derive(Clone)]
+#[contracttype]
+pub struct UserBalance {
+    pub shares: i128,
+    pub q4w: Vec<Q4W>,
+}
+
+/// The subset of the backstop contract interface used by the share token
+#[allow(dead_code)]
+#[contractclient(name = "BackstopClient")]
+pub trait Backstop {
+    /// Fetch the balance of backstop shares of a pool for the user
+    fn user_balance(e: Env, pool: Address, user: Address) -> UserBalance;
+
+    /// (Only Share Token) Transfer un-queued backstop shares of a pool from "from" to "to"
+    fn share_token_transfer(e: Env, pool: Address, from: Address, to: Address, amount: i128);
+}
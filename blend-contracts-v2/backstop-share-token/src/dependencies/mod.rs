@@ -0,0 +1,4 @@
+mod backstop;
+pub use backstop::BackstopClient;
+#[cfg(test)]
+pub use backstop::UserBalance;
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the backstop share token contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Backstop share token specific errors start at 1400.
+pub enum ShareTokenError {
+    // Common Errors
+    InternalError = 1,
+    NegativeAmountError = 8,
+    AllowanceError = 9,
+
+    // Backstop Share Token
+    InvalidExpirationLedger = 1400,
+    NotSupported = 1401,
+}
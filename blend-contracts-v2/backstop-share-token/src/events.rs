@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct ShareTokenEvents {}
+
+impl ShareTokenEvents {
+    /// Emitted when an allowance is set
+    ///
+    /// - topics - `["approve", from: Address, spender: Address]`
+    /// - data - `[amount: i128, expiration_ledger: u32]`
+    ///
+    /// ### Arguments
+    /// * `from` - The owner of the shares
+    /// * `spender` - The address allowed to spend the shares
+    /// * `amount` - The amount of shares allowed to be spent
+    /// * `expiration_ledger` - The ledger the allowance expires at
+    pub fn approve(e: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        let topics = (Symbol::new(e, "approve"), from, spender);
+        e.events().publish(topics, (amount, expiration_ledger));
+    }
+
+    /// Emitted when shares are transferred
+    ///
+    /// - topics - `["transfer", from: Address, to: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `from` - The address sending shares
+    /// * `to` - The address receiving shares
+    /// * `amount` - The amount of shares transferred
+    pub fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, "transfer"), from, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when shares are minted by a backstop deposit or dequeue
+    ///
+    /// - topics - `["mint", admin: Address, to: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `admin` - The backstop address
+    /// * `to` - The address receiving shares
+    /// * `amount` - The amount of shares minted
+    pub fn mint(e: &Env, admin: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, "mint"), admin, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when shares are burned by a backstop queue for withdrawal
+    ///
+    /// - topics - `["burn", from: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares were burned
+    /// * `amount` - The amount of shares burned
+    pub fn burn(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, "burn"), from);
+        e.events().publish(topics, amount);
+    }
+}
@@ -0,0 +1,15 @@
+#![no_std]
+
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
+mod contract;
+mod dependencies;
+mod errors;
+mod events;
+mod storage;
+mod testutils;
+
+pub use contract::*;
+pub use errors::ShareTokenError;
+pub use storage::{AllowanceDataKey, AllowanceValue, ShareTokenDataKey};
@@ -0,0 +1,134 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+/********** Ledger Thresholds **********/
+
+const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5s a ledger
+
+const LEDGER_THRESHOLD_INSTANCE: u32 = ONE_DAY_LEDGERS * 30; // ~ 30 days
+const LEDGER_BUMP_INSTANCE: u32 = LEDGER_THRESHOLD_INSTANCE + ONE_DAY_LEDGERS; // ~ 31 days
+
+/********** Storage Types **********/
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/********** Storage Key Types **********/
+
+const BACKSTOP_KEY: &str = "Backstop";
+const POOL_KEY: &str = "Pool";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ShareTokenDataKey {
+    Allowance(AllowanceDataKey),
+}
+
+/****************************
+**         Storage         **
+****************************/
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_INSTANCE, LEDGER_BUMP_INSTANCE);
+}
+
+/********** Instance Storage **********/
+
+/// Fetch the backstop id
+pub fn get_backstop(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, BACKSTOP_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the backstop id
+///
+/// ### Arguments
+/// * `backstop` - The ID of the backstop
+pub fn set_backstop(e: &Env, backstop: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, BACKSTOP_KEY), backstop);
+}
+
+/// Fetch the pool id whose backstop shares are represented by the token
+pub fn get_pool(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, POOL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the pool id whose backstop shares are represented by the token
+///
+/// ### Arguments
+/// * `pool` - The ID of the pool
+pub fn set_pool(e: &Env, pool: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, POOL_KEY), pool);
+}
+
+/********** Allowance **********/
+
+/// Fetch the allowance "spender" has over the shares of "from". Expired allowances are returned
+/// with an amount of zero.
+///
+/// ### Arguments
+/// * `from` - The owner of the shares
+/// * `spender` - The address allowed to spend the shares
+pub fn get_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = ShareTokenDataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    match e
+        .storage()
+        .temporary()
+        .get::<ShareTokenDataKey, AllowanceValue>(&key)
+    {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance,
+        Some(allowance) => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+/// Set the allowance "spender" has over the shares of "from"
+///
+/// ### Arguments
+/// * `from` - The owner of the shares
+/// * `spender` - The address allowed to spend the shares
+/// * `allowance` - The new allowance
+pub fn set_allowance(e: &Env, from: &Address, spender: &Address, allowance: &AllowanceValue) {
+    let key = ShareTokenDataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    e.storage()
+        .temporary()
+        .set::<ShareTokenDataKey, AllowanceValue>(&key, allowance);
+    if allowance.amount > 0 {
+        let live_for = allowance.expiration_ledger - e.ledger().sequence();
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
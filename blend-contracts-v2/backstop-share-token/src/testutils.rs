@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use crate::{dependencies::UserBalance, BackstopShareTokenClient, BackstopShareTokenContract};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger, LedgerInfo},
+    vec, Address, Env,
+};
+
+/// Set the ledger sequence, with entry TTLs that outlive any allowance used in tests
+pub(crate) fn set_ledger(e: &Env, sequence_number: u32) {
+    e.ledger().set(LedgerInfo {
+        protocol_version: 22,
+        sequence_number,
+        timestamp: 1500000000,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 17280 * 10,
+        min_persistent_entry_ttl: 17280 * 10,
+        max_entry_ttl: 3110400,
+    });
+}
+
+/// Create a share token wrapping shares of a generated pool in a mock backstop
+pub(crate) fn create_share_token<'a>(
+    e: &Env,
+) -> (BackstopShareTokenClient<'a>, MockBackstopClient<'a>) {
+    let backstop = e.register(MockBackstop {}, ());
+    let share_token = e.register(
+        BackstopShareTokenContract {},
+        (backstop.clone(), Address::generate(e)),
+    );
+    (
+        BackstopShareTokenClient::new(e, &share_token),
+        MockBackstopClient::new(e, &backstop),
+    )
+}
+
+/// A mock backstop that tracks un-queued shares for a single pool
+#[contract]
+pub(crate) struct MockBackstop;
+
+#[contractimpl]
+impl MockBackstop {
+    pub fn set_shares(e: Env, user: Address, shares: i128) {
+        e.storage().instance().set(&user, &shares);
+    }
+
+    pub fn user_balance(e: Env, _pool: Address, user: Address) -> UserBalance {
+        UserBalance {
+            shares: e.storage().instance().get(&user).unwrap_or(0),
+            q4w: vec![&e],
+        }
+    }
+
+    pub fn share_token_transfer(e: Env, _pool: Address, from: Address, to: Address, amount: i128) {
+        let from_shares: i128 = e.storage().instance().get(&from).unwrap_or(0);
+        let to_shares: i128 = e.storage().instance().get(&to).unwrap_or(0);
+        assert!(from_shares >= amount, "insufficient shares");
+        e.storage().instance().set(&from, &(from_shares - amount));
+        e.storage().instance().set(&to, &(to_shares + amount));
+    }
+}
@@ -2,10 +2,12 @@
 use cvlr_soroban::nondet_vec;
 use soroban_sdk::{contracttype, panic_with_error, vec, Env, Vec};
 
-use crate::{
-    constants::{MAX_Q4W_SIZE, Q4W_LOCK_TIME},
-    errors::BackstopError,
-};
+use crate::errors::BackstopError;
+
+#[cfg(feature = "certora_storage_ghost")]
+use crate::certora_specs::summaries::storage;
+#[cfg(not(feature = "certora_storage_ghost"))]
+use crate::storage;
 
 #[cfg(feature = "certora_vec_q4w")] // @note changed
 use crate::certora_specs::summaries::vec_q4w::{VecQ4W, vec_q4w_empty};
@@ -92,7 +94,7 @@ impl UserBalance {
         if self.shares < to_q {
             panic_with_error!(e, BackstopError::BalanceError);
         }
-        if self.q4w.len() >= MAX_Q4W_SIZE {
+        if self.q4w.len() >= storage::get_max_q4w_size(e) {
             panic_with_error!(e, BackstopError::TooManyQ4WEntries);
         }
         self.shares = self.shares + to_q; // MUTANT
@@ -100,7 +102,7 @@ impl UserBalance {
         // user has enough tokens to withdrawal, add Q4W
         let new_q4w = Q4W {
             amount: to_q,
-            exp: e.ledger().timestamp() + Q4W_LOCK_TIME,
+            exp: e.ledger().timestamp() + storage::get_q4w_lock_time(e),
         };
         self.q4w.push_back(new_q4w.clone());
     }
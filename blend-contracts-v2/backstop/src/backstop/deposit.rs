@@ -13,9 +13,12 @@ use crate::certora_specs::mocks::token::TokenClient;
 #[cfg(not(feature = "certora_token_mock"))]
 use sep_41_token::TokenClient;
 
-use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env, Vec};
 
-use super::require_is_from_pool_factory;
+use crate::{constants::SCALAR_7, dependencies::CometClient};
+
+use super::{require_is_from_pool_factory, share_token};
 
 /// Perform a deposit into the backstop module
 pub fn execute_deposit(e: &Env, from: &Address, pool_address: &Address, amount: i128) -> i128 {
@@ -51,9 +54,70 @@ pub fn execute_deposit(e: &Env, from: &Address, pool_address: &Address, amount:
     storage::set_pool_balance(e, pool_address, &pool_balance);
     storage::set_user_balance(e, pool_address, from, &user_balance);
 
+    share_token::notify_mint(e, pool_address, from, to_mint);
+
     to_mint
 }
 
+/// Perform a deposit of the backstop token's underlying BLND and USDC into the backstop module.
+/// The underlying tokens are joined into the backstop token for "from" before being deposited.
+/// If both tokens are given they are joined together in the backstop token's ratio, leaving any
+/// excess of either token with "from".
+///
+/// Returns a tuple of (backstop_tokens_deposited, shares_minted)
+pub fn execute_deposit_underlying(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    blnd_in: i128,
+    usdc_in: i128,
+    min_lp_out: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, blnd_in);
+    require_nonnegative(e, usdc_in);
+
+    let comet_client = CometClient::new(e, &storage::get_backstop_token(e));
+    let blnd_token = storage::get_blnd_token(e);
+    let usdc_token = storage::get_usdc_token(e);
+    let mut lp_tokens_out: i128 = 0;
+    if blnd_in > 0 && usdc_in > 0 {
+        // join with both tokens to avoid the swap fee of single sided joins
+        let total_supply = comet_client.get_total_supply();
+        let blnd_lp_out = blnd_in
+            .fixed_mul_floor(total_supply, comet_client.get_balance(&blnd_token))
+            .unwrap_optimized();
+        let usdc_lp_out = usdc_in
+            .fixed_mul_floor(total_supply, comet_client.get_balance(&usdc_token))
+            .unwrap_optimized();
+        // @dev: the backstop token rounds the join ratio up, so leave a margin of one unit of its
+        //       7 decimal precision to ensure the tokens pulled don't exceed the amounts given
+        lp_tokens_out = (blnd_lp_out.min(usdc_lp_out) - total_supply / SCALAR_7 - 1).max(0);
+        if lp_tokens_out > 0 {
+            let mut max_amounts_in = Vec::new(e);
+            for token in comet_client.get_tokens().iter() {
+                if token == blnd_token {
+                    max_amounts_in.push_back(blnd_in);
+                } else {
+                    max_amounts_in.push_back(usdc_in);
+                }
+            }
+            comet_client.join_pool(&lp_tokens_out, &max_amounts_in, from);
+        }
+    } else if blnd_in > 0 {
+        lp_tokens_out =
+            comet_client.dep_tokn_amt_in_get_lp_tokns_out(&blnd_token, &blnd_in, &0, from);
+    } else if usdc_in > 0 {
+        lp_tokens_out =
+            comet_client.dep_tokn_amt_in_get_lp_tokns_out(&usdc_token, &usdc_in, &0, from);
+    }
+    if lp_tokens_out < min_lp_out {
+        panic_with_error!(e, &BackstopError::SlippageError);
+    }
+
+    let to_mint = execute_deposit(e, from, pool_address, lp_tokens_out);
+    (lp_tokens_out, to_mint)
+}
+
 #[cfg(test)]
 mod tests {
     use soroban_sdk::{testutils::Address as _, Address};
@@ -61,7 +125,10 @@ mod tests {
     use crate::{
         backstop::execute_donate,
         constants::SCALAR_7,
-        testutils::{create_backstop, create_backstop_token, create_mock_pool_factory},
+        testutils::{
+            create_backstop, create_backstop_token, create_blnd_token, create_comet_lp_pool,
+            create_mock_pool_factory, create_usdc_token,
+        },
     };
 
     use super::*;
@@ -306,4 +373,109 @@ mod tests {
     //         execute_deposit(&e, &samwise, &pool_0_id, SCALAR_7 / 10 - 1);
     //     });
     // }
+
+    #[test]
+    fn test_execute_deposit_underlying() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_0_id = Address::generate(&e);
+
+        let (blnd_address, blnd_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, lp_client) =
+            create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        blnd_client.mint(&samwise, &10_0000000);
+        usdc_client.mint(&samwise, &1_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_token(&e, &lp_address);
+
+            // joins both tokens in the pool's ratio of 1000 BLND / 25 USDC for 100 shares
+            let (lp_tokens, shares) =
+                execute_deposit_underlying(&e, &samwise, &pool_0_id, 10_0000000, 1_0000000, 0);
+            assert_eq!(lp_tokens, 0_9999899);
+            assert_eq!(shares, lp_tokens);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(new_pool_balance.shares, shares);
+            assert_eq!(new_pool_balance.tokens, lp_tokens);
+            let new_user_balance = storage::get_user_balance(&e, &pool_0_id, &samwise);
+            assert_eq!(new_user_balance.shares, shares);
+
+            assert_eq!(lp_client.balance(&backstop_address), lp_tokens);
+            assert_eq!(lp_client.balance(&samwise), 0);
+            // the excess USDC is left with samwise
+            assert!(blnd_client.balance(&samwise) < 0_0001000);
+            let usdc_balance = usdc_client.balance(&samwise);
+            assert!(usdc_balance > 0_7400000 && usdc_balance < 0_7600000);
+        });
+    }
+
+    #[test]
+    fn test_execute_deposit_underlying_single_sided() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_0_id = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, lp_client) =
+            create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        usdc_client.mint(&samwise, &1_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_token(&e, &lp_address);
+
+            let (lp_tokens, shares) =
+                execute_deposit_underlying(&e, &samwise, &pool_0_id, 0, 1_0000000, 0);
+            assert!(lp_tokens > 0);
+            assert_eq!(shares, lp_tokens);
+
+            assert_eq!(lp_client.balance(&backstop_address), lp_tokens);
+            assert_eq!(usdc_client.balance(&samwise), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1011)")]
+    fn test_execute_deposit_underlying_slippage() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_0_id = Address::generate(&e);
+
+        let (blnd_address, blnd_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, _) = create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        blnd_client.mint(&samwise, &10_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_token(&e, &lp_address);
+
+            execute_deposit_underlying(&e, &samwise, &pool_0_id, 10_0000000, 0, 100_0000000);
+        });
+    }
 }
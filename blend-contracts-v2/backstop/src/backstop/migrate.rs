@@ -0,0 +1,258 @@
+#[cfg(feature = "certora_storage_ghost")] // @note changed
+use crate::{certora_specs::summaries::storage, contract::require_nonnegative, BackstopError};
+#[cfg(not(feature = "certora_storage_ghost"))]
+use crate::{contract::require_nonnegative, storage, BackstopError};
+
+#[cfg(feature = "certora_emission_summarized")]
+use crate::certora_specs::summaries::emissions;
+#[cfg(not(feature = "certora_emission_summarized"))]
+use crate::emissions;
+
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::{require_is_from_pool_factory, share_token};
+
+/// Perform a migration of expired queued shares from the backstop of "pool_a" into the backstop of "pool_b".
+/// The backstop tokens backing the shares are moved between the pool balances without leaving the contract.
+///
+/// Returns a tuple of (backstop_tokens_migrated, pool_b_shares_minted)
+pub fn execute_migrate(
+    e: &Env,
+    from: &Address,
+    pool_a: &Address,
+    pool_b: &Address,
+    amount: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, amount);
+    if pool_a == pool_b || from == pool_b || from == &e.current_contract_address() {
+        panic_with_error!(e, &BackstopError::BadRequest)
+    }
+
+    // withdraw the expired shares from pool a
+    let mut pool_a_balance = storage::get_pool_balance(e, pool_a);
+    let mut user_a_balance = storage::get_user_balance(e, pool_a, from);
+
+    emissions::update_emissions(e, pool_a, &pool_a_balance, from, &user_a_balance);
+
+    user_a_balance.withdraw_shares(e, amount);
+
+    let to_migrate = pool_a_balance.convert_to_tokens(amount);
+    if to_migrate == 0 {
+        panic_with_error!(e, &BackstopError::InvalidTokenWithdrawAmount);
+    }
+    pool_a_balance.withdraw(e, to_migrate, amount);
+
+    storage::set_user_balance(e, pool_a, from, &user_a_balance);
+    storage::set_pool_balance(e, pool_a, &pool_a_balance);
+
+    // deposit the withdrawn tokens into pool b
+    let mut pool_b_balance = storage::get_pool_balance(e, pool_b);
+    require_is_from_pool_factory(e, pool_b, pool_b_balance.shares);
+    let mut user_b_balance = storage::get_user_balance(e, pool_b, from);
+
+    emissions::update_emissions(e, pool_b, &pool_b_balance, from, &user_b_balance);
+
+    let to_mint = pool_b_balance.convert_to_shares(to_migrate);
+    if to_mint <= 0 {
+        panic_with_error!(e, &BackstopError::InvalidShareMintAmount);
+    }
+    pool_b_balance.deposit(to_migrate, to_mint);
+    user_b_balance.add_shares(to_mint);
+
+    storage::set_pool_balance(e, pool_b, &pool_b_balance);
+    storage::set_user_balance(e, pool_b, from, &user_b_balance);
+
+    share_token::notify_mint(e, pool_b, from, to_mint);
+
+    (to_migrate, to_mint)
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        Address,
+    };
+
+    use crate::{
+        backstop::{execute_deposit, execute_draw, execute_queue_withdrawal},
+        testutils::{create_backstop, create_backstop_token, create_mock_pool_factory},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_execute_migrate() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_a = Address::generate(&e);
+        let pool_b = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+        backstop_token_client.mint(&frodo, &50_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_a);
+        mock_pool_factory_client.set_pool(&pool_b);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        // setup pool b to have incurred a loss
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_a, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_a, 40_0000000);
+            execute_deposit(&e, &frodo, &pool_b, 50_0000000);
+            execute_draw(&e, &pool_b, 25_0000000, &bombadil);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            let (tokens, shares) = execute_migrate(&e, &samwise, &pool_a, &pool_b, 40_0000000);
+            assert_eq!(tokens, 40_0000000);
+            assert_eq!(shares, 80_0000000);
+
+            let pool_a_balance = storage::get_pool_balance(&e, &pool_a);
+            assert_eq!(pool_a_balance.shares, 60_0000000);
+            assert_eq!(pool_a_balance.tokens, 60_0000000);
+            assert_eq!(pool_a_balance.q4w, 0);
+            let user_a_balance = storage::get_user_balance(&e, &pool_a, &samwise);
+            assert_eq!(user_a_balance.shares, 60_0000000);
+            assert_eq!(user_a_balance.q4w.len(), 0);
+
+            let pool_b_balance = storage::get_pool_balance(&e, &pool_b);
+            assert_eq!(pool_b_balance.shares, 130_0000000);
+            assert_eq!(pool_b_balance.tokens, 65_0000000);
+            assert_eq!(pool_b_balance.q4w, 0);
+            let user_b_balance = storage::get_user_balance(&e, &pool_b, &samwise);
+            assert_eq!(user_b_balance.shares, 80_0000000);
+            assert_eq!(user_b_balance.q4w.len(), 0);
+
+            // no tokens left the backstop
+            assert_eq!(
+                backstop_token_client.balance(&backstop_address),
+                125_0000000
+            );
+            assert_eq!(backstop_token_client.balance(&samwise), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1001)")]
+    fn test_execute_migrate_not_expired() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_a = Address::generate(&e);
+        let pool_b = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_a);
+        mock_pool_factory_client.set_pool(&pool_b);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_a, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_a, 40_0000000);
+
+            execute_migrate(&e, &samwise, &pool_a, &pool_b, 40_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_migrate_same_pool() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_a = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_migrate(&e, &samwise, &pool_a, &pool_a, 40_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1004)")]
+    fn test_execute_migrate_not_pool() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_a = Address::generate(&e);
+        let pool_b = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_a);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_a, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_a, 40_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_migrate(&e, &samwise, &pool_a, &pool_b, 40_0000000);
+        });
+    }
+}
@@ -1,16 +1,29 @@
 mod deposit;
-pub use deposit::execute_deposit;
+pub use deposit::{execute_deposit, execute_deposit_underlying};
 
 mod fund_management;
 pub use fund_management::{execute_donate, execute_draw};
 
 mod withdrawal;
-pub use withdrawal::{execute_dequeue_withdrawal, execute_queue_withdrawal, execute_withdraw};
+pub use withdrawal::{
+    execute_dequeue_withdrawal, execute_emergency_withdraw, execute_instant_withdraw,
+    execute_queue_withdrawal, execute_withdraw, execute_withdraw_expired,
+    execute_withdraw_underlying,
+};
+
+mod migrate;
+pub use migrate::execute_migrate;
+
+mod share_token;
+pub use share_token::{execute_deploy_share_token, execute_share_token_transfer, notify_mint};
+
+mod transfer;
+pub use transfer::execute_transfer_shares;
 
 mod pool;
 pub use pool::{
     load_pool_backstop_data, require_is_from_pool_factory, require_pool_above_threshold,
-    PoolBackstopData, PoolBalance,
+    require_pool_emergency_exit, require_pool_instant_withdraw, PoolBackstopData, PoolBalance,
 };
 
 mod user;
@@ -1,17 +1,17 @@
 use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, Address, Env, Vec};
 
 #[cfg(feature = "certora_storage_ghost")] // @note changed
 use crate::{
     constants::SCALAR_7,
-    dependencies::CometClient,
+    dependencies::{CometClient, PoolClient, Reserve},
     errors::BackstopError,
     certora_specs::summaries::storage,
 };
 #[cfg(not(feature = "certora_storage_ghost"))]
 use crate::{
     constants::SCALAR_7,
-    dependencies::{CometClient, PoolFactoryClient},
+    dependencies::{CometClient, PoolClient, PoolFactoryClient, Reserve},
     errors::BackstopError,
     storage,
 };
@@ -136,6 +136,63 @@ pub fn require_pool_above_threshold(pool_backstop_data: &PoolBackstopData) -> bo
     saturating_pool_pc >= threshold_pc
 }
 
+/// Require that backstop depositors of the pool can exit queued shares before they expire. This
+/// is only allowed if the pool was frozen by its admin, no reserve has outstanding borrows, the
+/// backstop holds no liabilities in the pool, and no bad debt auction is ongoing, such that the
+/// backstop can no longer be drawn from.
+///
+/// ### Panics
+/// If the pool does not allow an emergency exit
+pub fn require_pool_emergency_exit(e: &Env, pool_address: &Address) {
+    let pool_client = PoolClient::new(e, pool_address);
+    let (pool_config, reserves) = pool_client.get_market();
+    // status 4 - admin frozen
+    if pool_config.status != 4 {
+        panic_with_error!(e, BackstopError::EmergencyExitNotAllowed);
+    }
+    if has_outstanding_borrows(&reserves) || has_backstop_debt(e, &pool_client) {
+        panic_with_error!(e, BackstopError::EmergencyExitNotAllowed);
+    }
+}
+
+/// Require that backstop depositors of the pool can instantly withdraw un-queued shares. This is
+/// only allowed if the pool is active, no reserve has outstanding borrows, the backstop holds no
+/// liabilities in the pool, and no bad debt auction is ongoing.
+///
+/// ### Panics
+/// If the pool does not allow an instant withdrawal
+pub fn require_pool_instant_withdraw(e: &Env, pool_address: &Address) {
+    let pool_client = PoolClient::new(e, pool_address);
+    let (pool_config, reserves) = pool_client.get_market();
+    // status 0 - admin active, status 1 - active
+    if pool_config.status > 1 {
+        panic_with_error!(e, BackstopError::InstantWithdrawNotAllowed);
+    }
+    if has_outstanding_borrows(&reserves) || has_backstop_debt(e, &pool_client) {
+        panic_with_error!(e, BackstopError::InstantWithdrawNotAllowed);
+    }
+}
+
+/// Check if any reserve of the pool has outstanding borrows, which can still create bad debt
+/// for the backstop
+fn has_outstanding_borrows(reserves: &Vec<Reserve>) -> bool {
+    let mut total_d_supply: i128 = 0;
+    for reserve in reserves.iter() {
+        total_d_supply += reserve.data.d_supply;
+    }
+    total_d_supply != 0
+}
+
+/// Check if the backstop holds liabilities in the pool or has an ongoing bad debt auction
+fn has_backstop_debt(e: &Env, pool_client: &PoolClient) -> bool {
+    let backstop = e.current_contract_address();
+    if !pool_client.get_positions(&backstop).liabilities.is_empty() {
+        return true;
+    }
+    // auction type 1 - bad debt auction. Fetching an auction fails if it does not exist.
+    pool_client.try_get_auction(&1, &backstop).is_ok()
+}
+
 /// The pool's backstop balances
 #[derive(Clone)]
 #[contracttype]
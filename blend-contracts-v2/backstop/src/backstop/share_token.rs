@@ -0,0 +1,250 @@
+use crate::{dependencies::ShareTokenClient, errors::BackstopError, storage};
+
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, Env};
+
+use super::{require_is_from_pool_factory, transfer::transfer_shares};
+
+/// Perform a deployment of the share token for a pool's backstop
+///
+/// Returns the address of the share token
+pub fn execute_deploy_share_token(e: &Env, pool_address: &Address) -> Address {
+    if storage::get_share_token(e, pool_address).is_some() {
+        panic_with_error!(e, &BackstopError::BadRequest);
+    }
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    require_is_from_pool_factory(e, pool_address, pool_balance.shares);
+
+    let salt = e.crypto().sha256(&pool_address.clone().to_xdr(e));
+    let share_token = e
+        .deployer()
+        .with_current_contract(salt.to_bytes())
+        .deploy_v2(
+            storage::get_share_token_hash(e),
+            (e.current_contract_address(), pool_address.clone()),
+        );
+    storage::set_share_token(e, pool_address, &share_token);
+    share_token
+}
+
+/// Perform a transfer of un-queued backstop shares initiated by the pool's share token
+pub fn execute_share_token_transfer(
+    e: &Env,
+    pool_address: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    match storage::get_share_token(e, pool_address) {
+        Some(share_token) => share_token.require_auth(),
+        None => panic_with_error!(e, &BackstopError::BadRequest),
+    }
+
+    // the share token emits its own transfer event, so it is not notified
+    transfer_shares(e, from, to, pool_address, amount);
+}
+
+/// Notify the pool's share token, if one exists, that shares were credited to "to"
+pub fn notify_mint(e: &Env, pool_address: &Address, to: &Address, amount: i128) {
+    if let Some(share_token) = storage::get_share_token(e, pool_address) {
+        ShareTokenClient::new(e, &share_token).backstop_mint(to, &amount);
+    }
+}
+
+/// Notify the pool's share token, if one exists, that shares were removed from "from"
+pub fn notify_burn(e: &Env, pool_address: &Address, from: &Address, amount: i128) {
+    if let Some(share_token) = storage::get_share_token(e, pool_address) {
+        ShareTokenClient::new(e, &share_token).backstop_burn(from, &amount);
+    }
+}
+
+/// Notify the pool's share token, if one exists, that shares were moved from "from" to "to"
+pub fn notify_transfer(
+    e: &Env,
+    pool_address: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    if let Some(share_token) = storage::get_share_token(e, pool_address) {
+        ShareTokenClient::new(e, &share_token).backstop_transfer(from, to, &amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        Address,
+    };
+
+    use crate::{
+        backstop::{
+            execute_deposit, execute_dequeue_withdrawal, execute_queue_withdrawal,
+            execute_transfer_shares,
+        },
+        testutils::{
+            create_backstop, create_backstop_token, create_mock_pool_factory,
+            create_share_token_hash,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_execute_deploy_share_token() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        create_share_token_hash(&e, &backstop_address);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            let share_token = execute_deploy_share_token(&e, &pool_address);
+            assert_eq!(
+                storage::get_share_token(&e, &pool_address),
+                Some(share_token.clone())
+            );
+
+            let share_token_client = ShareTokenClient::new(&e, &share_token);
+            assert_eq!(share_token_client.backstop(), backstop_address);
+            assert_eq!(share_token_client.pool(), pool_address);
+            assert_eq!(share_token_client.decimals(), 7);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_deploy_share_token_already_deployed() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        create_share_token_hash(&e, &backstop_address);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deploy_share_token(&e, &pool_address);
+            execute_deploy_share_token(&e, &pool_address);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1004)")]
+    fn test_execute_deploy_share_token_not_pool() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        create_share_token_hash(&e, &backstop_address);
+
+        create_mock_pool_factory(&e, &backstop_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deploy_share_token(&e, &pool_address);
+        });
+    }
+
+    #[test]
+    fn test_share_token_tracks_backstop_shares() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        create_share_token_hash(&e, &backstop_address);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let share_token = e.as_contract(&backstop_address, || {
+            execute_deploy_share_token(&e, &pool_address)
+        });
+        let share_token_client = ShareTokenClient::new(&e, &share_token);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_dequeue_withdrawal(&e, &samwise, &pool_address, 10_0000000);
+            execute_transfer_shares(&e, &samwise, &frodo, &pool_address, 15_0000000);
+        });
+        assert_eq!(share_token_client.balance(&samwise), 65_0000000);
+        assert_eq!(share_token_client.balance(&frodo), 15_0000000);
+
+        // transfers through the share token move backstop shares
+        share_token_client.transfer(&frodo, &samwise, &5_0000000);
+        assert_eq!(share_token_client.balance(&samwise), 70_0000000);
+        assert_eq!(share_token_client.balance(&frodo), 10_0000000);
+
+        share_token_client.approve(&samwise, &bombadil, &20_0000000, &1000);
+        share_token_client.transfer_from(&bombadil, &samwise, &frodo, &20_0000000);
+        assert_eq!(share_token_client.allowance(&samwise, &bombadil), 0);
+        assert_eq!(share_token_client.balance(&samwise), 50_0000000);
+        assert_eq!(share_token_client.balance(&frodo), 30_0000000);
+
+        e.as_contract(&backstop_address, || {
+            let samwise_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(samwise_balance.shares, 50_0000000);
+            assert_eq!(samwise_balance.q4w.len(), 1);
+            let frodo_balance = storage::get_user_balance(&e, &pool_address, &frodo);
+            assert_eq!(frodo_balance.shares, 30_0000000);
+
+            let pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(pool_balance.shares, 100_0000000);
+            assert_eq!(pool_balance.q4w, 20_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_share_token_transfer_no_share_token() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_share_token_transfer(&e, &pool_address, &samwise, &frodo, 10_0000000);
+        });
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(feature = "certora_storage_ghost")] // @note changed
+use crate::{certora_specs::summaries::storage, contract::require_nonnegative, BackstopError};
+#[cfg(not(feature = "certora_storage_ghost"))]
+use crate::{contract::require_nonnegative, storage, BackstopError};
+
+#[cfg(feature = "certora_emission_summarized")]
+use crate::certora_specs::summaries::emissions;
+#[cfg(not(feature = "certora_emission_summarized"))]
+use crate::emissions;
+
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::share_token;
+
+/// Perform a transfer of un-queued backstop shares from "from" to "to"
+pub fn execute_transfer_shares(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    pool_address: &Address,
+    amount: i128,
+) {
+    transfer_shares(e, from, to, pool_address, amount);
+
+    share_token::notify_transfer(e, pool_address, from, to, amount);
+}
+
+/// Move un-queued backstop shares from "from" to "to", settling emissions for both users
+pub(super) fn transfer_shares(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    pool_address: &Address,
+    amount: i128,
+) {
+    require_nonnegative(e, amount);
+    if from == to || to == pool_address || to == &e.current_contract_address() {
+        panic_with_error!(e, &BackstopError::BadRequest)
+    }
+
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    let mut from_balance = storage::get_user_balance(e, pool_address, from);
+    let mut to_balance = storage::get_user_balance(e, pool_address, to);
+
+    // update emissions for both users before their share balances change
+    emissions::update_emissions(e, pool_address, &pool_balance, from, &from_balance);
+    emissions::update_emissions(e, pool_address, &pool_balance, to, &to_balance);
+
+    from_balance.remove_shares(e, amount);
+    to_balance.add_shares(amount);
+
+    storage::set_user_balance(e, pool_address, from, &from_balance);
+    storage::set_user_balance(e, pool_address, to, &to_balance);
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        Address,
+    };
+
+    use crate::{
+        backstop::{execute_deposit, execute_queue_withdrawal},
+        testutils::{create_backstop, create_backstop_token, create_mock_pool_factory},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_execute_transfer_shares() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+        backstop_token_client.mint(&frodo, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_deposit(&e, &frodo, &pool_address, 25_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 20_0000000);
+
+            execute_transfer_shares(&e, &samwise, &frodo, &pool_address, 30_0000000);
+
+            let samwise_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(samwise_balance.shares, 50_0000000);
+            assert_eq!(samwise_balance.q4w.len(), 1);
+            let frodo_balance = storage::get_user_balance(&e, &pool_address, &frodo);
+            assert_eq!(frodo_balance.shares, 55_0000000);
+            assert_eq!(frodo_balance.q4w.len(), 0);
+
+            let pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(pool_balance.shares, 125_0000000);
+            assert_eq!(pool_balance.tokens, 125_0000000);
+            assert_eq!(pool_balance.q4w, 20_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_execute_transfer_shares_queued_shares() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 20_0000000);
+
+            execute_transfer_shares(&e, &samwise, &frodo, &pool_address, 80_0000001);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_transfer_shares_to_self() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+
+            execute_transfer_shares(&e, &samwise, &samwise, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #8)")]
+    fn test_execute_transfer_shares_negative_amount() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_transfer_shares(&e, &samwise, &frodo, &pool_address, -1);
+        });
+    }
+}
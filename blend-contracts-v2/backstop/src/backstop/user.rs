@@ -1,11 +1,13 @@
 #[cfg(not(feature = "certora_vec_q4w"))] // @note changed
 use cvlr_soroban::nondet_vec;
-use soroban_sdk::{contracttype, panic_with_error, vec, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, vec, Env, Vec};
 
-use crate::{
-    constants::{MAX_Q4W_SIZE, Q4W_LOCK_TIME},
-    errors::BackstopError,
-};
+use crate::{constants::Q4W_BUCKET, errors::BackstopError};
+
+#[cfg(feature = "certora_storage_ghost")] // @note changed
+use crate::certora_specs::summaries::storage;
+#[cfg(not(feature = "certora_storage_ghost"))]
+use crate::storage;
 
 #[cfg(feature = "certora_vec_q4w")] // @note changed
 use crate::certora_specs::summaries::vec_q4w::{VecQ4W, vec_q4w_empty};
@@ -77,34 +79,87 @@ impl UserBalance {
         self.shares += to_add;
     }
 
+    /// Remove shares from the user
+    ///
+    /// ### Arguments
+    /// * `to_remove` - The amount of shares to remove from the user
+    ///
+    /// ### Errors
+    /// If the amount to remove is greater than the available shares
+    pub fn remove_shares(&mut self, e: &Env, to_remove: i128) {
+        if self.shares < to_remove {
+            panic_with_error!(e, BackstopError::BalanceError);
+        }
+        self.shares -= to_remove;
+    }
+
     /***** Withdrawal Queue Management *****/
 
-    /// Queue new shares for withdraw for the user
+    /// Queue new shares for withdraw for the user. The new Q4W entry is locked for the
+    /// backstop's configured Q4W lock time.
     ///
-    /// Returns the new Q4W object
+    /// To keep the queue compact, all expired entries are collapsed into a single entry, and
+    /// the new shares are merged into the last entry if it is still locked and both expire in the
+    /// same day. Merged entries keep the latest expiration, so shares never unlock earlier than
+    /// scheduled.
     ///
     /// ### Arguments
     /// * `to_q` - The amount of new shares to queue for withdraw
     ///
     /// ### Errors
-    /// If the amount to queue is greater than the available shares
+    /// If the amount to queue is greater than the available shares, or if the user already
+    /// has the backstop's maximum amount of Q4W entries
     pub fn queue_shares_for_withdrawal(&mut self, e: &Env, to_q: i128) {
         if self.shares < to_q {
             panic_with_error!(e, BackstopError::BalanceError);
         }
-        if self.q4w.len() >= MAX_Q4W_SIZE {
-            panic_with_error!(e, BackstopError::TooManyQ4WEntries);
-        }
         self.shares = self.shares - to_q;
+        self.collapse_expired_q4w(e);
 
         // user has enough tokens to withdrawal, add Q4W
+        let new_exp = e.ledger().timestamp() + storage::get_q4w_lock_time(e);
+        if let Some(mut last) = self.q4w.last() {
+            // never merge into collapsed expired shares, as that would re-lock them
+            if last.exp > e.ledger().timestamp() && last.exp / Q4W_BUCKET == new_exp / Q4W_BUCKET {
+                self.q4w.pop_back_unchecked();
+                last.amount += to_q;
+                last.exp = last.exp.max(new_exp);
+                self.q4w.push_back(last);
+                return;
+            }
+        }
+        if self.q4w.len() >= storage::get_max_q4w_size(e) {
+            panic_with_error!(e, BackstopError::TooManyQ4WEntries);
+        }
         let new_q4w = Q4W {
             amount: to_q,
-            exp: e.ledger().timestamp() + Q4W_LOCK_TIME,
+            exp: new_exp,
         };
         self.q4w.push_back(new_q4w.clone());
     }
 
+    /// Collapse all expired Q4W entries at the front of the queue into a single entry
+    fn collapse_expired_q4w(&mut self, e: &Env) {
+        let now = e.ledger().timestamp();
+        let mut expired: Option<Q4W> = None;
+        while let Some(front) = self.q4w.get(0) {
+            if front.exp > now {
+                break;
+            }
+            self.q4w.pop_front_unchecked();
+            expired = Some(match expired {
+                Some(merged) => Q4W {
+                    amount: merged.amount + front.amount,
+                    exp: merged.exp.max(front.exp),
+                },
+                None => front,
+            });
+        }
+        if let Some(merged) = expired {
+            self.q4w.push_front(merged);
+        }
+    }
+
     /// Withdraw shares from the withdrawal queue
     ///
     /// ### Arguments
@@ -144,6 +199,39 @@ impl UserBalance {
         }
     }
 
+    /// Unlock all queued shares by collapsing the withdrawal queue into a single entry that
+    /// expires immediately
+    ///
+    /// Returns the amount of shares unlocked
+    pub fn unlock_all_q4w(&mut self, e: &Env) -> i128 {
+        let mut total: i128 = 0;
+        while !self.q4w.is_empty() {
+            total += self.q4w.pop_front_unchecked().amount;
+        }
+        if total > 0 {
+            self.q4w.push_back(Q4W {
+                amount: total,
+                exp: e.ledger().timestamp(),
+            });
+        }
+        total
+    }
+
+    /// Fetch the amount of queued shares that are currently eligible to withdraw, and the
+    /// expiration of the next queued withdrawal that is still locked, if any
+    pub fn withdrawable_shares(&self, e: &Env) -> (i128, Option<u64>) {
+        let mut withdrawable: i128 = 0;
+        for index in 0..self.q4w.len() {
+            let cur_q4w = self.q4w.get(index).unwrap_optimized();
+            if cur_q4w.exp <= e.ledger().timestamp() {
+                withdrawable += cur_q4w.amount;
+            } else {
+                return (withdrawable, Some(cur_q4w.exp));
+            }
+        }
+        (withdrawable, None)
+    }
+
     /// Dequeue shares from the withdrawal queue. Dequeues the most recently queued shares first.
     ///
     /// ### Arguments
@@ -183,7 +271,7 @@ impl UserBalance {
 
 #[cfg(test)]
 mod tests {
-    use crate::testutils::assert_eq_vec_q4w;
+    use crate::testutils::{assert_eq_vec_q4w, create_backstop};
 
     use super::*;
     use soroban_sdk::{
@@ -208,11 +296,39 @@ mod tests {
         assert_eq!(user.shares, to_add + 100);
     }
 
+    #[test]
+    fn test_remove_shares() {
+        let e = Env::default();
+
+        let mut user = UserBalance {
+            shares: 100,
+            q4w: vec![&e],
+        };
+
+        user.remove_shares(&e, 40);
+
+        assert_eq!(user.shares, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_remove_shares_over_balance() {
+        let e = Env::default();
+
+        let mut user = UserBalance {
+            shares: 100,
+            q4w: vec![&e],
+        };
+
+        user.remove_shares(&e, 101);
+    }
+
     /********** Q4W Management **********/
 
     #[test]
     fn test_q4w_none_queued() {
         let e = Env::default();
+        let backstop_address = create_backstop(&e);
 
         let mut user = UserBalance {
             shares: 1000,
@@ -231,7 +347,9 @@ mod tests {
         });
 
         let to_queue = 500;
-        user.queue_shares_for_withdrawal(&e, to_queue);
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
         assert_eq_vec_q4w(
             &user.q4w,
             &vec![
@@ -247,6 +365,7 @@ mod tests {
     #[test]
     fn test_q4w_new_placed_last() {
         let e = Env::default();
+        let backstop_address = create_backstop(&e);
 
         let mut cur_q4w = vec![
             &e,
@@ -272,7 +391,9 @@ mod tests {
         });
 
         let to_queue = 500;
-        user.queue_shares_for_withdrawal(&e, to_queue);
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
         cur_q4w.push_back(Q4W {
             amount: to_queue,
             exp: 11000000 + 17 * 24 * 60 * 60,
@@ -283,6 +404,7 @@ mod tests {
     #[test]
     fn test_q4w_new_to_max_works() {
         let e = Env::default();
+        let backstop_address = create_backstop(&e);
         let exp = 12592000;
         let mut cur_q4w = vec![&e];
         for i in 0..19 {
@@ -308,7 +430,9 @@ mod tests {
         });
 
         let to_queue = 500;
-        user.queue_shares_for_withdrawal(&e, to_queue);
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
         cur_q4w.push_back(Q4W {
             amount: to_queue,
             exp: 11000000 + 17 * 24 * 60 * 60,
@@ -320,6 +444,7 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #1007)")]
     fn test_q4w_new_over_max_panics() {
         let e = Env::default();
+        let backstop_address = create_backstop(&e);
 
         let exp = 12592000;
         let mut cur_q4w = vec![&e];
@@ -346,13 +471,16 @@ mod tests {
         });
 
         let to_queue = 500;
-        user.queue_shares_for_withdrawal(&e, to_queue);
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
     }
 
     #[test]
     #[should_panic(expected = "Error(Contract, #10)")]
     fn test_q4w_over_shares_panics() {
         let e = Env::default();
+        let backstop_address = create_backstop(&e);
 
         let cur_q4w = vec![
             &e,
@@ -378,7 +506,291 @@ mod tests {
         });
 
         let to_queue = 801;
-        user.queue_shares_for_withdrawal(&e, to_queue);
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+    }
+
+    #[test]
+    fn test_q4w_uses_configured_lock_time() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![&e],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let to_queue = 500;
+        e.as_contract(&backstop_address, || {
+            storage::set_q4w_lock_time(&e, &(24 * 60 * 60));
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: to_queue,
+                    exp: 10000 + 24 * 60 * 60,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1007)")]
+    fn test_q4w_over_configured_max_panics() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 200,
+                    exp: 12592000,
+                },
+                Q4W {
+                    amount: 200,
+                    exp: 12592001,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11000000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let to_queue = 500;
+        e.as_contract(&backstop_address, || {
+            storage::set_max_q4w_size(&e, &2);
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+    }
+
+    #[test]
+    fn test_q4w_merges_same_day_entry() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        // new entry expires at 12468800, which is in the same day bucket
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 200,
+                    exp: 12450000,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11000000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let to_queue = 500;
+        e.as_contract(&backstop_address, || {
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+        assert_eq!(user.shares, 500);
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: 700,
+                    exp: 11000000 + 17 * 24 * 60 * 60,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_q4w_merge_keeps_later_exp() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        // existing entry expires later in the same day bucket than the new entry
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 200,
+                    exp: 12470000,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11000000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let to_queue = 500;
+        e.as_contract(&backstop_address, || {
+            storage::set_max_q4w_size(&e, &1);
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: 700,
+                    exp: 12470000,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_q4w_collapses_expired_entries() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 100,
+                    exp: 10000000,
+                },
+                Q4W {
+                    amount: 150,
+                    exp: 10500000,
+                },
+                Q4W {
+                    amount: 200,
+                    exp: 11000000,
+                },
+                Q4W {
+                    amount: 250,
+                    exp: 11500000,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11000000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let to_queue = 300;
+        e.as_contract(&backstop_address, || {
+            storage::set_max_q4w_size(&e, &3);
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+        assert_eq!(user.shares, 700);
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: 450,
+                    exp: 11000000,
+                },
+                Q4W {
+                    amount: 250,
+                    exp: 11500000,
+                },
+                Q4W {
+                    amount: to_queue,
+                    exp: 11000000 + 17 * 24 * 60 * 60,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_q4w_does_not_merge_into_expired_entry() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 100,
+                    exp: 100 * 24 * 60 * 60 + 100,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 100 * 24 * 60 * 60 + 200,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        // the new entry expires in the same day as the expired entry
+        let to_queue = 300;
+        e.as_contract(&backstop_address, || {
+            storage::set_q4w_lock_time(&e, &(60 * 60));
+            user.queue_shares_for_withdrawal(&e, to_queue);
+        });
+        assert_eq!(user.shares, 700);
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: 100,
+                    exp: 100 * 24 * 60 * 60 + 100,
+                },
+                Q4W {
+                    amount: to_queue,
+                    exp: 100 * 24 * 60 * 60 + 200 + 60 * 60,
+                },
+            ],
+        );
     }
 
     // withdraw_shares
@@ -810,4 +1222,55 @@ mod tests {
         let to_dequeue = 376;
         user.dequeue_shares(&e, to_dequeue);
     }
+
+    #[test]
+    fn test_withdrawable_shares() {
+        let e = Env::default();
+
+        let user = UserBalance {
+            shares: 1000,
+            q4w: vec![
+                &e,
+                Q4W {
+                    amount: 125,
+                    exp: 10000000,
+                },
+                Q4W {
+                    amount: 200,
+                    exp: 11190000,
+                },
+                Q4W {
+                    amount: 50,
+                    exp: 11191000,
+                },
+            ],
+        };
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11190000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        assert_eq!(user.withdrawable_shares(&e), (325, Some(11191000)));
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 11191000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        assert_eq!(user.withdrawable_shares(&e), (375, None));
+
+        let empty_user = UserBalance::env_default(&e);
+        assert_eq!(empty_user.withdrawable_shares(&e), (0, None));
+    }
 }
@@ -13,9 +13,19 @@ use crate::certora_specs::mocks::token::TokenClient;
 #[cfg(not(feature = "certora_token_mock"))]
 use sep_41_token::TokenClient;
 
-use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env};
+use cast::i128;
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, vec, Address, Env};
 
-use super::Q4W;
+use crate::{
+    constants::{MAX_INSTANT_WITHDRAW_Q4W_PCT, SCALAR_7},
+    dependencies::CometClient,
+};
+
+use super::{
+    load_pool_backstop_data, require_pool_above_threshold, require_pool_emergency_exit,
+    require_pool_instant_withdraw, share_token, Q4W,
+};
 
 /// Perform a queue for withdraw from the backstop module
 pub fn execute_queue_withdrawal(
@@ -38,6 +48,8 @@ pub fn execute_queue_withdrawal(
     storage::set_user_balance(e, pool_address, from, &user_balance);
     storage::set_pool_balance(e, pool_address, &pool_balance);
 
+    share_token::notify_burn(e, pool_address, from, amount);
+
     user_balance.q4w.last().unwrap_optimized()
 }
 
@@ -57,6 +69,8 @@ pub fn execute_dequeue_withdrawal(e: &Env, from: &Address, pool_address: &Addres
 
     storage::set_user_balance(e, pool_address, from, &user_balance);
     storage::set_pool_balance(e, pool_address, &pool_balance);
+
+    share_token::notify_mint(e, pool_address, from, amount);
 }
 
 /// Perform a withdraw from the backstop module
@@ -92,9 +106,125 @@ pub fn execute_withdraw(e: &Env, from: &Address, pool_address: &Address, amount:
     to_return
 }
 
+/// Perform a withdraw of all currently expired queued shares from the backstop module
+///
+/// Returns a tuple of (shares_withdrawn, backstop_tokens_withdrawn)
+///
+/// ### Panics
+/// If no queued shares have expired
+pub fn execute_withdraw_expired(e: &Env, from: &Address, pool_address: &Address) -> (i128, i128) {
+    let user_balance = storage::get_user_balance(e, pool_address, from);
+    let (amount, _) = user_balance.withdrawable_shares(e);
+    if amount == 0 {
+        panic_with_error!(e, &BackstopError::NoExpiredShares);
+    }
+    let to_return = execute_withdraw(e, from, pool_address, amount);
+    (amount, to_return)
+}
+
+/// Perform an emergency withdraw of all queued shares from the backstop module, regardless of
+/// their expiration. Only allowed if the pool is admin frozen and can no longer draw from the backstop.
+///
+/// Returns a tuple of (shares_withdrawn, backstop_tokens_withdrawn)
+pub fn execute_emergency_withdraw(e: &Env, from: &Address, pool_address: &Address) -> (i128, i128) {
+    require_pool_emergency_exit(e, pool_address);
+
+    let mut user_balance = storage::get_user_balance(e, pool_address, from);
+    let amount = user_balance.unlock_all_q4w(e);
+    storage::set_user_balance(e, pool_address, from, &user_balance);
+
+    let to_return = execute_withdraw(e, from, pool_address, amount);
+    (amount, to_return)
+}
+
+/// Perform an instant withdraw of un-queued shares from the backstop module, bypassing the Q4W
+/// lock. The instant withdraw fee remains in the pool's backstop.
+///
+/// Returns a tuple of (backstop_tokens_withdrawn, fee)
+pub fn execute_instant_withdraw(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    amount: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, amount);
+    require_pool_instant_withdraw(e, pool_address);
+
+    let mut pool_balance = storage::get_pool_balance(e, pool_address);
+    let mut user_balance = storage::get_user_balance(e, pool_address, from);
+
+    // update emissions
+    emissions::update_emissions(e, pool_address, &pool_balance, from, &user_balance);
+
+    user_balance.remove_shares(e, amount);
+
+    let tokens = pool_balance.convert_to_tokens(amount);
+    let fee = tokens
+        .fixed_mul_ceil(i128(storage::get_instant_withdraw_fee(e)), SCALAR_7)
+        .unwrap_optimized();
+    let to_return = tokens - fee;
+    if to_return <= 0 {
+        panic_with_error!(e, &BackstopError::InvalidTokenWithdrawAmount);
+    }
+    // the shares are queued and withdrawn at once, leaving the fee in the pool's backstop
+    pool_balance.queue_for_withdraw(amount);
+    pool_balance.withdraw(e, to_return, amount);
+
+    storage::set_user_balance(e, pool_address, from, &user_balance);
+    storage::set_pool_balance(e, pool_address, &pool_balance);
+
+    // the pool's backstop must remain healthy after the withdrawal
+    let pool_backstop_data = load_pool_backstop_data(e, pool_address);
+    if pool_backstop_data.q4w_pct >= MAX_INSTANT_WITHDRAW_Q4W_PCT
+        || !require_pool_above_threshold(&pool_backstop_data)
+    {
+        panic_with_error!(e, &BackstopError::InstantWithdrawNotAllowed);
+    }
+
+    share_token::notify_burn(e, pool_address, from, amount);
+
+    let backstop_token_client = TokenClient::new(e, &storage::get_backstop_token(e));
+    backstop_token_client.transfer(&e.current_contract_address(), from, &to_return);
+
+    (to_return, fee)
+}
+
+/// Perform a withdraw from the backstop module, and exit the withdrawn backstop tokens into
+/// the underlying BLND and USDC for "from"
+///
+/// Returns a tuple of (backstop_tokens_withdrawn, blnd_out, usdc_out)
+pub fn execute_withdraw_underlying(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    amount: i128,
+    min_blnd_out: i128,
+    min_usdc_out: i128,
+) -> (i128, i128, i128) {
+    let to_return = execute_withdraw(e, from, pool_address, amount);
+
+    let blnd_token = storage::get_blnd_token(e);
+    let usdc_token = storage::get_usdc_token(e);
+    let blnd_client = TokenClient::new(e, &blnd_token);
+    let usdc_client = TokenClient::new(e, &usdc_token);
+    let blnd_balance = blnd_client.balance(from);
+    let usdc_balance = usdc_client.balance(from);
+
+    let backstop_token = storage::get_backstop_token(e);
+    CometClient::new(e, &backstop_token).exit_pool(&to_return, &vec![e, 0, 0], from);
+
+    let blnd_out = blnd_client.balance(from) - blnd_balance;
+    let usdc_out = usdc_client.balance(from) - usdc_balance;
+    if blnd_out < min_blnd_out || usdc_out < min_usdc_out {
+        panic_with_error!(e, &BackstopError::SlippageError);
+    }
+    (to_return, blnd_out, usdc_out)
+}
+
 #[cfg(test)]
 mod tests {
     use soroban_sdk::{
+        map,
         testutils::{Address as _, Ledger, LedgerInfo},
         vec, Address,
     };
@@ -102,7 +232,9 @@ mod tests {
     use crate::{
         backstop::{execute_deposit, execute_donate, execute_draw},
         testutils::{
-            assert_eq_vec_q4w, create_backstop, create_backstop_token, create_mock_pool_factory,
+            assert_eq_vec_q4w, create_backstop, create_backstop_token, create_blnd_token,
+            create_comet_lp_pool, create_comet_lp_pool_with_tokens_per_share, create_mock_pool,
+            create_mock_pool_factory, create_usdc_token,
         },
     };
 
@@ -521,4 +653,836 @@ mod tests {
             execute_withdraw(&e, &samwise, &pool_address, 1_0000000);
         });
     }
+
+    #[test]
+    fn test_execute_withdraw_expired() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 5_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 300,
+            timestamp: 10000 + 24 * 60 * 60,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 12_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 400,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            let (shares, tokens) = execute_withdraw_expired(&e, &samwise, &pool_address);
+            assert_eq!(shares, 35_0000000);
+            assert_eq!(tokens, 35_0000000);
+
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(new_user_balance.shares, 53_0000000);
+            assert_eq_vec_q4w(
+                &new_user_balance.q4w,
+                &vec![
+                    &e,
+                    Q4W {
+                        amount: 12_0000000,
+                        exp: 10000 + 18 * 24 * 60 * 60,
+                    },
+                ],
+            );
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.q4w, 12_0000000);
+            assert_eq!(new_pool_balance.shares, 65_0000000);
+            assert_eq!(new_pool_balance.tokens, 65_0000000);
+
+            assert_eq!(backstop_token_client.balance(&backstop_address), 65_0000000);
+            assert_eq!(backstop_token_client.balance(&samwise), 35_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1014)")]
+    fn test_execute_withdraw_expired_none_expired() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_withdraw_expired(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1014)")]
+    fn test_execute_withdraw_expired_empty_queue() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_withdraw_expired(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    fn test_execute_emergency_withdraw() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 300,
+            timestamp: 10000 + 24 * 60 * 60,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 12_0000000);
+        });
+
+        mock_pool_client.set_status(&4);
+
+        e.as_contract(&backstop_address, || {
+            let (shares, tokens) = execute_emergency_withdraw(&e, &samwise, &pool_address);
+            assert_eq!(shares, 42_0000000);
+            assert_eq!(tokens, 42_0000000);
+
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(new_user_balance.shares, 58_0000000);
+            assert_eq!(new_user_balance.q4w.len(), 0);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.q4w, 0);
+            assert_eq!(new_pool_balance.shares, 58_0000000);
+            assert_eq!(new_pool_balance.tokens, 58_0000000);
+
+            assert_eq!(backstop_token_client.balance(&backstop_address), 58_0000000);
+            assert_eq!(backstop_token_client.balance(&samwise), 42_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_emergency_withdraw_not_admin_frozen() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_status(&5);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_emergency_withdraw(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_emergency_withdraw_backstop_liabilities() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_status(&4);
+        mock_pool_client.set_liabilities(&backstop_address, &map![&e, (0, 1_0000000)]);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_emergency_withdraw(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_emergency_withdraw_outstanding_borrows() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_status(&4);
+        mock_pool_client.set_d_supply(&1_0000000);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_emergency_withdraw(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_emergency_withdraw_bad_debt_auction() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_status(&4);
+        mock_pool_client.set_auction(&1, &backstop_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 30_0000000);
+            execute_emergency_withdraw(&e, &samwise, &pool_address);
+        });
+    }
+
+    #[test]
+    fn test_execute_instant_withdraw() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, _) = create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+        let lp_client = TokenClient::new(&e, &lp_address);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+
+            let (tokens, fee) = execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+            assert_eq!(tokens, 9_5000000);
+            assert_eq!(fee, 0_5000000);
+
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &bombadil);
+            assert_eq!(new_user_balance.shares, 70_0000000);
+            assert_eq!(new_user_balance.q4w.len(), 0);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.q4w, 0);
+            assert_eq!(new_pool_balance.shares, 70_0000000);
+            assert_eq!(new_pool_balance.tokens, 70_5000000);
+
+            assert_eq!(lp_client.balance(&backstop_address), 70_5000000);
+            assert_eq!(lp_client.balance(&bombadil), 29_5000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_over_max_q4w_pct() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_queue_withdrawal(&e, &bombadil, &pool_address, 20_0000000);
+
+            // 20 / 60 shares queued for withdrawal after the exit
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 20_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_under_threshold() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            5_0000000,
+            &usdc_address,
+            0_0500000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_pool_not_active() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_status(&3);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_backstop_liabilities() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_liabilities(&backstop_address, &map![&e, (0, 1_0000000)]);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_outstanding_borrows() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_d_supply(&1_0000000);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_instant_withdraw_bad_debt_auction() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (blnd_address, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_address,
+            10_000_0000000,
+            &usdc_address,
+            250_0000000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        mock_pool_client.set_auction(&1, &backstop_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &bombadil, &pool_address, 80_0000000);
+            execute_instant_withdraw(&e, &bombadil, &pool_address, 10_0000000);
+        });
+    }
+
+    #[test]
+    fn test_execute_withdraw_underlying() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_address, blnd_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, lp_client) =
+            create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        blnd_client.mint(&samwise, &200_0000000);
+        usdc_client.mint(&samwise, &5_0000000);
+        lp_client.join_pool(&10_0000000, &vec![&e, 200_0000000, 5_0000000], &samwise);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_token(&e, &lp_address);
+            execute_deposit(&e, &samwise, &pool_address, 10_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 4_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let blnd_balance = blnd_client.balance(&samwise);
+        let usdc_balance = usdc_client.balance(&samwise);
+        e.as_contract(&backstop_address, || {
+            let (tokens_out, blnd_out, usdc_out) =
+                execute_withdraw_underlying(&e, &samwise, &pool_address, 4_0000000, 1, 1);
+            assert_eq!(tokens_out, 4_0000000);
+            assert!(blnd_out > 0);
+            assert!(usdc_out > 0);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.shares, 6_0000000);
+            assert_eq!(new_pool_balance.tokens, 6_0000000);
+            assert_eq!(new_pool_balance.q4w, 0);
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(new_user_balance.shares, 6_0000000);
+            assert_eq!(new_user_balance.q4w.len(), 0);
+
+            assert_eq!(lp_client.balance(&samwise), 0);
+            assert_eq!(lp_client.balance(&backstop_address), 6_0000000);
+            assert_eq!(blnd_client.balance(&samwise), blnd_balance + blnd_out);
+            assert_eq!(usdc_client.balance(&samwise), usdc_balance + usdc_out);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1011)")]
+    fn test_execute_withdraw_underlying_slippage() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_address, blnd_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_address, lp_client) =
+            create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        blnd_client.mint(&samwise, &200_0000000);
+        usdc_client.mint(&samwise, &5_0000000);
+        lp_client.join_pool(&10_0000000, &vec![&e, 200_0000000, 5_0000000], &samwise);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_token(&e, &lp_address);
+            execute_deposit(&e, &samwise, &pool_address, 10_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 4_0000000);
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_withdraw_underlying(&e, &samwise, &pool_address, 4_0000000, 1_000_0000000, 0);
+        });
+    }
 }
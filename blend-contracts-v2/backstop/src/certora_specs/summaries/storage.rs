@@ -59,4 +59,19 @@ pub fn get_usdc_token(_e: &Env) -> Address {
 #[allow(dead_code)]
 pub fn get_emitter(_e: &Env) -> Address {
     nondet_address()
-}
\ No newline at end of file
+}
+// Q4W configuration is read from the contract's instance storage, as set by the constructor
+#[allow(dead_code)]
+pub fn get_q4w_lock_time(e: &Env) -> u64 {
+    crate::storage::get_q4w_lock_time(e)
+}
+
+#[allow(dead_code)]
+pub fn get_max_q4w_size(e: &Env) -> u32 {
+    crate::storage::get_max_q4w_size(e)
+}
+
+#[allow(dead_code)]
+pub fn get_instant_withdraw_fee(e: &Env) -> u32 {
+    crate::storage::get_instant_withdraw_fee(e)
+}
@@ -1,7 +1,6 @@
 use crate::backstop::{PoolBalance, UserBalance};
 use crate::certora_specs::mocks;
 use soroban_sdk::{Address, Env, unwrap::UnwrapOptimized};
-use crate::constants::Q4W_LOCK_TIME;
 
 #[cfg(feature = "certora_storage_ghost")] 
 use crate::certora_specs::summaries::storage;
@@ -115,7 +114,7 @@ pub fn valid_state_pb_q4w_leq_shares (
     pb.q4w <= pb.shares
 }
 
-// The expiration time (exp) in any Q4W entry must not exceed timestamp + Q4W_LOCK_TIME
+// The expiration time (exp) in any Q4W entry must not exceed timestamp + the stored Q4W lock time
 pub fn valid_state_ub_q4w_expiration(
     e: Env,
     pool: Address,
@@ -124,7 +123,7 @@ pub fn valid_state_ub_q4w_expiration(
     let ub: UserBalance = storage::get_user_balance(&e, &pool, &user);
 
     if ub.q4w.len() != 0 {
-        let max_timestamp = e.ledger().timestamp() + Q4W_LOCK_TIME;
+        let max_timestamp = e.ledger().timestamp() + storage::get_q4w_lock_time(&e);
         let entry0 = ub.q4w.get(0).unwrap_optimized();
         if entry0.exp > max_timestamp {
             return false;
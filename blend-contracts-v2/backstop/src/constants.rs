@@ -7,12 +7,21 @@ pub const SCALAR_14: i128 = 1_0000000_0000000;
 /// The maximum reward zone size
 pub const MAX_RZ_SIZE: u32 = 50;
 
-/// The maximum amount of active Q4W entries that a user can have against a single backstop.
-pub const MAX_Q4W_SIZE: u32 = 20;
-
-/// The time in seconds that a Q4W entry is locked for (17 days).
-pub const Q4W_LOCK_TIME: u64 = 17 * 24 * 60 * 60;
-
 /// The maximum amount of backfilled emissions that can be emitted.
 /// Represents between 3-4 months worth of token emissions.
-pub const MAX_BACKFILLED_EMISSIONS: i128 = 10_000_000 * SCALAR_7;
\ No newline at end of file
+pub const MAX_BACKFILLED_EMISSIONS: i128 = 10_000_000 * SCALAR_7;
+
+/// The minimum length of time in seconds a Q4W entry can be locked for (1 day)
+pub const MIN_Q4W_LOCK_TIME: u64 = 24 * 60 * 60;
+
+/// The length of time in seconds Q4W entries are bucketed by when merged (1 day)
+pub const Q4W_BUCKET: u64 = 24 * 60 * 60;
+
+/// The minimum fee taken on instant withdrawals, expressed in 7 decimals (0.1%)
+#[allow(clippy::zero_prefixed_literal)]
+pub const MIN_INSTANT_WITHDRAW_FEE: u32 = 0_0010000;
+
+/// The maximum percentage of a pool's backstop shares that can be queued for withdrawal after an
+/// instant withdrawal, expressed in 7 decimals
+#[allow(clippy::zero_prefixed_literal)]
+pub const MAX_INSTANT_WITHDRAW_Q4W_PCT: i128 = 0_3000000;
\ No newline at end of file
@@ -1,8 +1,11 @@
 use crate::{
-    backstop::{self, load_pool_backstop_data, PoolBackstopData, UserBalance, Q4W}, constants::{MAX_BACKFILLED_EMISSIONS, SCALAR_7}, dependencies::EmitterClient, emissions, errors::BackstopError, events::BackstopEvents, storage
+    backstop::{self, load_pool_backstop_data, PoolBackstopData, UserBalance, Q4W}, constants::{MAX_BACKFILLED_EMISSIONS, MIN_INSTANT_WITHDRAW_FEE, MIN_Q4W_LOCK_TIME, SCALAR_7}, dependencies::EmitterClient, emissions, errors::BackstopError, events::BackstopEvents, storage
 };
 
-use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, Vec};
+use cast::i128;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, BytesN, Env, Vec,
+};
 
 /// ### Backstop
 ///
@@ -24,9 +27,35 @@ pub trait Backstop {
     /// * `amount` - The amount of tokens to deposit
     fn deposit(e: Env, from: Address, pool_address: Address, amount: i128) -> i128;
 
+    /// Deposit the backstop token's underlying BLND and USDC from "from" into the backstop of a pool.
+    /// The underlying tokens are joined into the backstop token before being deposited. If both
+    /// tokens are given, they are joined in the backstop token's ratio and any excess of either
+    /// token is left with "from".
+    ///
+    /// Returns the number of backstop pool shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The address depositing into the backstop
+    /// * `pool_address` - The address of the pool
+    /// * `blnd_in` - The amount of BLND to deposit
+    /// * `usdc_in` - The amount of USDC to deposit
+    /// * `min_lp_out` - The minimum amount of backstop tokens the underlying tokens must join for
+    ///
+    /// ### Panics
+    /// If less than `min_lp_out` backstop tokens are received for the underlying tokens
+    fn deposit_underlying(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        blnd_in: i128,
+        usdc_in: i128,
+        min_lp_out: i128,
+    ) -> i128;
+
     /// Queue deposited pool shares from "from" for withdraw from a backstop of a pool
     ///
-    /// Returns the created queue for withdrawal
+    /// Returns the queue for withdrawal entry holding the shares. If the shares were merged into
+    /// the user's last entry, the returned amount is the merged entry's total.
     ///
     /// ### Arguments
     /// * `from` - The address whose deposits are being queued for withdrawal
@@ -52,6 +81,100 @@ pub trait Backstop {
     /// * `amount` - The amount of shares to withdraw
     fn withdraw(e: Env, from: Address, pool_address: Address, amount: i128) -> i128;
 
+    /// Withdraw all currently expired shares from "from"s withdraw queue for a backstop of a pool
+    ///
+    /// Returns the amount of tokens returned
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    ///
+    /// ### Errors
+    /// If "from" has no expired shares to withdraw
+    fn withdraw_expired(e: Env, from: Address, pool_address: Address) -> i128;
+
+    /// Withdraw all shares from "from"s withdraw queue for a backstop of a pool, regardless of
+    /// when they expire
+    ///
+    /// Returns the amount of tokens returned
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    ///
+    /// ### Errors
+    /// If the pool is not admin frozen, if the pool has outstanding borrows, if the backstop has
+    /// liabilities or an ongoing bad debt auction in the pool, or if "from" has no queued shares
+    /// to withdraw
+    fn emergency_withdraw(e: Env, from: Address, pool_address: Address) -> i128;
+
+    /// Withdraw un-queued shares from "from" for a backstop of a pool without waiting for the
+    /// Q4W lock time. The backstop's instant withdraw fee is kept by the pool's backstop.
+    ///
+    /// Returns the amount of tokens returned
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of shares to withdraw
+    ///
+    /// ### Errors
+    /// If the pool is not active, if the pool has outstanding borrows, if the backstop has
+    /// liabilities or an ongoing bad debt auction in the pool, if "from" does not have enough
+    /// un-queued shares, or if the pool's backstop would be queued for withdrawal too heavily or
+    /// fall below the threshold after the withdrawal
+    fn instant_withdraw(e: Env, from: Address, pool_address: Address, amount: i128) -> i128;
+
+    /// Withdraw shares from "from"s withdraw queue for a backstop of a pool, and exit the
+    /// withdrawn backstop tokens into the underlying BLND and USDC
+    ///
+    /// Returns a tuple of the amount of BLND and USDC received
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of shares to withdraw
+    /// * `min_blnd_out` - The minimum amount of BLND to receive
+    /// * `min_usdc_out` - The minimum amount of USDC to receive
+    ///
+    /// ### Panics
+    /// If less than `min_blnd_out` BLND or `min_usdc_out` USDC is received
+    fn withdraw_underlying(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        min_blnd_out: i128,
+        min_usdc_out: i128,
+    ) -> (i128, i128);
+
+    /// Transfer un-queued backstop shares of a pool from "from" to "to"
+    ///
+    /// ### Arguments
+    /// * `from` - The address sending the shares
+    /// * `to` - The address receiving the shares
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If "from" does not have enough un-queued shares, or if "to" is "from", the pool, or the backstop
+    fn transfer_shares(e: Env, from: Address, to: Address, pool_address: Address, amount: i128);
+
+    /// Migrate expired shares from "from"s withdraw queue for the backstop of "pool_a" into the
+    /// backstop of "pool_b", without the backstop tokens leaving the backstop
+    ///
+    /// Returns the number of pool b backstop shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being migrated
+    /// * `pool_a` - The address of the pool to migrate shares from
+    /// * `pool_b` - The address of the pool to migrate shares to
+    /// * `amount` - The amount of pool a shares to migrate
+    ///
+    /// ### Errors
+    /// If the shares are not expired in the withdraw queue of pool a, or if pool b is invalid
+    fn migrate(e: Env, from: Address, pool_a: Address, pool_b: Address, amount: i128) -> i128;
+
     /// Fetch the balance of backstop shares of a pool for the user
     ///
     /// ### Arguments
@@ -59,6 +182,23 @@ pub trait Backstop {
     /// * `user` - The user to fetch the balance for
     fn user_balance(e: Env, pool: Address, user: Address) -> UserBalance;
 
+    /// Fetch the amount of queued shares the user can currently withdraw from the backstop of a pool,
+    /// and the timestamp the next locked queued withdrawal unlocks at, if any
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The user to fetch the withdrawable shares for
+    fn withdrawable_shares(e: Env, pool: Address, user: Address) -> (i128, Option<u64>);
+
+    /// Extend the TTL of a user's balance and emissions data for a pool without modifying them
+    ///
+    /// Permissionless, allowing keepers to keep a user's entries from being archived
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool
+    /// * `user` - The user to extend the entries for
+    fn bump_user_balance(e: Env, pool: Address, user: Address);
+
     /// Fetch the backstop data for the pool
     ///
     /// Return a summary of the pool's backstop data
@@ -151,6 +291,43 @@ pub trait Backstop {
     /// If the `pool_address` is not valid, backstop does not have sufficient allowance from `from`, or if the pool does not
     /// authorize the call
     fn donate(e: Env, from: Address, pool_address: Address, amount: i128);
+
+    /********** Share Token **********/
+
+    /// Deploy the SEP-41 share token that wraps the un-queued backstop shares of a pool
+    ///
+    /// Returns the address of the share token
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    ///
+    /// ### Errors
+    /// If the pool already has a share token or the pool was not deployed by the pool factory
+    fn deploy_share_token(e: Env, pool_address: Address) -> Address;
+
+    /// Fetch the share token of a pool's backstop, if one has been deployed
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    fn share_token(e: Env, pool_address: Address) -> Option<Address>;
+
+    /// (Only Share Token) Transfer un-queued backstop shares of a pool from "from" to "to"
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address sending the shares
+    /// * `to` - The address receiving the shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the pool's share token does not authorize the call, or if the transfer is invalid
+    fn share_token_transfer(
+        e: Env,
+        pool_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    );
 }
 
 #[contractimpl]
@@ -164,6 +341,10 @@ impl BackstopContract {
     /// * `usdc_token` - The USDC token ID
     /// * `pool_factory` - The pool factory ID
     /// * `drop_list` - The list of addresses to distribute initial BLND to and the percent of the distribution they should receive
+    /// * `share_token_hash` - The wasm hash of the backstop share token contract
+    /// * `q4w_lock_time` - The time in seconds that a Q4W entry is locked for. Must be at least one day.
+    /// * `max_q4w_size` - The maximum amount of active Q4W entries that a user can have against a single backstop
+    /// * `instant_withdraw_fee` - The fee taken on instant withdrawals, expressed in 7 decimals. Must be at least 0.1%.
     pub fn __constructor(
         e: Env,
         backstop_token: Address,
@@ -172,6 +353,10 @@ impl BackstopContract {
         usdc_token: Address,
         pool_factory: Address,
         drop_list: Vec<(Address, i128)>,
+        share_token_hash: BytesN<32>,
+        q4w_lock_time: u64,
+        max_q4w_size: u32,
+        instant_withdraw_fee: u32,
     ) {
         storage::set_backstop_token(&e, &backstop_token);
         storage::set_blnd_token(&e, &blnd_token);
@@ -186,6 +371,18 @@ impl BackstopContract {
         }
         storage::set_drop_list(&e, &drop_list);
         storage::set_emitter(&e, &emitter);
+        storage::set_share_token_hash(&e, &share_token_hash);
+        if q4w_lock_time < MIN_Q4W_LOCK_TIME || max_q4w_size == 0 {
+            panic_with_error!(&e, BackstopError::BadRequest);
+        }
+        storage::set_q4w_lock_time(&e, &q4w_lock_time);
+        storage::set_max_q4w_size(&e, &max_q4w_size);
+        if instant_withdraw_fee < MIN_INSTANT_WITHDRAW_FEE
+            || i128(instant_withdraw_fee) >= SCALAR_7
+        {
+            panic_with_error!(&e, BackstopError::BadRequest);
+        }
+        storage::set_instant_withdraw_fee(&e, &instant_withdraw_fee);
     }
 }
 
@@ -206,6 +403,30 @@ impl Backstop for BackstopContract {
         to_mint
     }
 
+    fn deposit_underlying(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        blnd_in: i128,
+        usdc_in: i128,
+        min_lp_out: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (amount, to_mint) = backstop::execute_deposit_underlying(
+            &e,
+            &from,
+            &pool_address,
+            blnd_in,
+            usdc_in,
+            min_lp_out,
+        );
+
+        BackstopEvents::deposit(&e, pool_address, from, amount, to_mint);
+        to_mint
+    }
+
     fn queue_withdrawal(e: Env, from: Address, pool_address: Address, amount: i128) -> Q4W {
         storage::extend_instance(&e);
         from.require_auth();
@@ -235,10 +456,93 @@ impl Backstop for BackstopContract {
         to_withdraw
     }
 
+    fn withdraw_expired(e: Env, from: Address, pool_address: Address) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (amount, to_withdraw) = backstop::execute_withdraw_expired(&e, &from, &pool_address);
+
+        BackstopEvents::withdraw(&e, pool_address, from, amount, to_withdraw);
+        to_withdraw
+    }
+
+    fn emergency_withdraw(e: Env, from: Address, pool_address: Address) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (amount, to_withdraw) = backstop::execute_emergency_withdraw(&e, &from, &pool_address);
+
+        BackstopEvents::withdraw(&e, pool_address, from, amount, to_withdraw);
+        to_withdraw
+    }
+
+    fn instant_withdraw(e: Env, from: Address, pool_address: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (to_withdraw, fee) =
+            backstop::execute_instant_withdraw(&e, &from, &pool_address, amount);
+
+        BackstopEvents::instant_withdraw(&e, pool_address, from, amount, to_withdraw, fee);
+        to_withdraw
+    }
+
+    fn withdraw_underlying(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        min_blnd_out: i128,
+        min_usdc_out: i128,
+    ) -> (i128, i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (to_withdraw, blnd_out, usdc_out) = backstop::execute_withdraw_underlying(
+            &e,
+            &from,
+            &pool_address,
+            amount,
+            min_blnd_out,
+            min_usdc_out,
+        );
+
+        BackstopEvents::withdraw(&e, pool_address, from, amount, to_withdraw);
+        (blnd_out, usdc_out)
+    }
+
+    fn transfer_shares(e: Env, from: Address, to: Address, pool_address: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        backstop::execute_transfer_shares(&e, &from, &to, &pool_address, amount);
+
+        BackstopEvents::transfer_shares(&e, pool_address, from, to, amount);
+    }
+
+    fn migrate(e: Env, from: Address, pool_a: Address, pool_b: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (to_migrate, to_mint) = backstop::execute_migrate(&e, &from, &pool_a, &pool_b, amount);
+
+        BackstopEvents::migrate(&e, pool_a, pool_b, from, amount, to_migrate, to_mint);
+        to_mint
+    }
+
     fn user_balance(e: Env, pool: Address, user: Address) -> UserBalance {
         storage::get_user_balance(&e, &pool, &user)
     }
 
+    fn withdrawable_shares(e: Env, pool: Address, user: Address) -> (i128, Option<u64>) {
+        storage::get_user_balance(&e, &pool, &user).withdrawable_shares(&e)
+    }
+
+    fn bump_user_balance(e: Env, pool: Address, user: Address) {
+        storage::extend_instance(&e);
+        storage::bump_user_balance(&e, &pool, &user);
+    }
+
     fn pool_data(e: Env, pool: Address) -> PoolBackstopData {
         load_pool_backstop_data(&e, &pool)
     }
@@ -320,6 +624,35 @@ impl Backstop for BackstopContract {
 
         BackstopEvents::donate(&e, pool_address, from, amount);
     }
+
+    /********** Share Token **********/
+
+    fn deploy_share_token(e: Env, pool_address: Address) -> Address {
+        storage::extend_instance(&e);
+
+        let share_token = backstop::execute_deploy_share_token(&e, &pool_address);
+
+        BackstopEvents::deploy_share_token(&e, pool_address, share_token.clone());
+        share_token
+    }
+
+    fn share_token(e: Env, pool_address: Address) -> Option<Address> {
+        storage::get_share_token(&e, &pool_address)
+    }
+
+    fn share_token_transfer(
+        e: Env,
+        pool_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        storage::extend_instance(&e);
+
+        backstop::execute_share_token_transfer(&e, &pool_address, &from, &to, amount);
+
+        BackstopEvents::transfer_shares(&e, pool_address, from, to, amount);
+    }
 }
 
 /// Require that an incoming amount is not negative
@@ -1,10 +1,22 @@
 mod pool_factory;
 pub use pool_factory::Client as PoolFactoryClient;
 
+mod pool;
+pub use pool::{PoolClient, Reserve};
+
+#[cfg(test)]
+pub use pool::{AuctionData, PoolConfig, Positions, ReserveConfig, ReserveData};
+
 mod comet;
 pub use comet::Client as CometClient;
 
 #[cfg(test)]
 pub use comet::WASM as COMET_WASM;
 
+mod share_token;
+pub use share_token::Client as ShareTokenClient;
+
+#[cfg(test)]
+pub use share_token::WASM as SHARE_TOKEN_WASM;
+
 pub use blend_contract_sdk::emitter::Client as EmitterClient;
@@ -0,0 +1,101 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Map, Vec};
+
+/// The pool's config
+///
+/// Mirrors the `PoolConfig` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct PoolConfig {
+    pub oracle: Address,
+    pub min_collateral: i128,
+    pub bstop_rate: u32,
+    pub status: u32,
+    pub max_positions: u32,
+}
+
+/// A user's positions in a pool
+///
+/// Mirrors the `Positions` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct Positions {
+    pub liabilities: Map<u32, i128>,
+    pub collateral: Map<u32, i128>,
+    pub supply: Map<u32, i128>,
+}
+
+/// An ongoing auction in a pool
+///
+/// Mirrors the `AuctionData` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct AuctionData {
+    pub bid: Map<Address, i128>,
+    pub lot: Map<Address, i128>,
+    pub block: u32,
+}
+
+/// A reserve's configuration
+///
+/// Mirrors the `ReserveConfig` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct ReserveConfig {
+    pub index: u32,
+    pub decimals: u32,
+    pub c_factor: u32,
+    pub l_factor: u32,
+    pub util: u32,
+    pub max_util: u32,
+    pub r_base: u32,
+    pub r_one: u32,
+    pub r_two: u32,
+    pub r_three: u32,
+    pub reactivity: u32,
+    pub collateral_cap: i128,
+    pub enabled: bool,
+}
+
+/// A reserve's data
+///
+/// Mirrors the `ReserveData` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct ReserveData {
+    pub d_rate: i128,
+    pub b_rate: i128,
+    pub ir_mod: i128,
+    pub b_supply: i128,
+    pub d_supply: i128,
+    pub backstop_credit: i128,
+    pub last_time: u64,
+}
+
+/// A reserve of a pool
+///
+/// Mirrors the `Reserve` type of the pool contract
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct Reserve {
+    pub asset: Address,
+    pub config: ReserveConfig,
+    pub data: ReserveData,
+    pub scalar: i128,
+}
+
+/// The subset of the pool contract interface used by the backstop
+#[allow(dead_code)]
+#[contractclient(name = "PoolClient")]
+pub trait Pool {
+    /// Fetch the pool's config
+    fn get_config(e: Env) -> PoolConfig;
+
+    /// Fetch the pool's config and all of its reserves, updated to the current ledger
+    fn get_market(e: Env) -> (PoolConfig, Vec<Reserve>);
+
+    /// Fetch the positions for an address
+    fn get_positions(e: Env, address: Address) -> Positions;
+
+    /// Fetch an auction from the ledger. Panics if the auction does not exist.
+    fn get_auction(e: Env, auction_type: u32, user: Address) -> AuctionData;
+}
@@ -1,4 +1,7 @@
-use crate::{dependencies::CometClient, errors::BackstopError, events::BackstopEvents, storage};
+use crate::{
+    backstop::notify_mint, dependencies::CometClient, errors::BackstopError,
+    events::BackstopEvents, storage,
+};
 use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{
     auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
@@ -67,6 +70,7 @@ pub fn execute_claim(e: &Env, from: &Address, pool_addresses: &Vec<Address>, to:
 
             storage::set_pool_balance(e, &pool_id, &pool_balance);
             storage::set_user_balance(e, &pool_id, to, &user_balance);
+            notify_mint(e, &pool_id, to, to_mint);
 
             BackstopEvents::deposit(e, pool_id, to.clone(), deposit_amount, to_mint);
         }
@@ -81,7 +85,10 @@ mod tests {
     use crate::{
         backstop::{PoolBalance, UserBalance},
         storage::{BackstopEmissionData, UserEmissionData},
-        testutils::{create_backstop, create_blnd_token, create_comet_lp_pool, create_usdc_token},
+        testutils::{
+            create_backstop, create_blnd_token, create_comet_lp_pool, create_mock_share_token,
+            create_usdc_token,
+        },
     };
 
     use super::*;
@@ -272,6 +279,86 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_claim_notifies_share_token() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let block_timestamp = 1500000000 + 12345;
+        e.ledger().set(LedgerInfo {
+            timestamp: block_timestamp,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_1_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (blnd_address, blnd_token_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_address, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        blnd_token_client.mint(&backstop_address, &100_0000000);
+        let (_, share_token_client) = create_mock_share_token(&e, &backstop_address, &pool_1_id);
+
+        let (lp_address, _) = create_comet_lp_pool(&e, &bombadil, &blnd_address, &usdc_address);
+        e.as_contract(&backstop_address, || {
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_1_id,
+                &BackstopEmissionData {
+                    expiration: 1500000000 + 7 * 24 * 60 * 60,
+                    eps: 0_10000000000000,
+                    index: 222220000000,
+                    last_time: 1500000000,
+                },
+            );
+            storage::set_user_emis_data(
+                &e,
+                &pool_1_id,
+                &samwise,
+                &UserEmissionData {
+                    index: 111110000000,
+                    accrued: 1_2345678,
+                },
+            );
+            storage::set_backstop_token(&e, &lp_address);
+            storage::set_blnd_token(&e, &blnd_address);
+            storage::set_pool_balance(
+                &e,
+                &pool_1_id,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 2_0000000,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1_id,
+                &samwise,
+                &UserBalance {
+                    shares: 9_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            let result = execute_claim(&e, &samwise, &vec![&e, pool_1_id.clone()], &frodo);
+            assert!(result > 0);
+
+            let frodo_balance = storage::get_user_balance(&e, &pool_1_id, &frodo);
+            assert!(frodo_balance.shares > 0);
+            assert_eq!(share_token_client.minted(&frodo), frodo_balance.shares);
+            assert_eq!(share_token_client.minted(&samwise), 0);
+        });
+    }
+
     #[test]
     fn test_claim_twice() {
         let e = Env::default();
@@ -28,4 +28,8 @@ pub enum BackstopError {
     NotInRewardZone = 1008,
     RewardZoneFull = 1009,
     MaxBackfillEmissions = 1010,
+    SlippageError = 1011,
+    EmergencyExitNotAllowed = 1012,
+    InstantWithdrawNotAllowed = 1013,
+    NoExpiredShares = 1014,
 }
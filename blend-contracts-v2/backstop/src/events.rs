@@ -75,6 +75,89 @@ impl BackstopEvents {
         e.events().publish(topics, (amount, tokens_out));
     }
 
+    /// Emitted when shares are instantly withdrawn from the backstop
+    ///
+    /// - topics - `["instant_withdraw", pool_address: Address, from: Address]`
+    /// - data - `[amount: i128, tokens_out: i128, fee: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user withdrawing tokens
+    /// * `amount` - The amount of backstop shares being burned
+    /// * `tokens_out` - The amount of tokens being withdrawn
+    /// * `fee` - The amount of tokens kept by the pool's backstop
+    pub fn instant_withdraw(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        amount: i128,
+        tokens_out: i128,
+        fee: i128,
+    ) {
+        let topics = (Symbol::new(e, "instant_withdraw"), pool_address, from);
+        e.events().publish(topics, (amount, tokens_out, fee));
+    }
+
+    /// Emitted when backstop shares are transferred between users
+    ///
+    /// - topics - `["transfer_shares", pool_address: Address, from: Address, to: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user sending shares
+    /// * `to` - The address of the user receiving shares
+    /// * `amount` - The amount of backstop shares transferred
+    pub fn transfer_shares(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        let topics = (Symbol::new(e, "transfer_shares"), pool_address, from, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when expired queued shares are migrated from one pool's backstop to another's
+    ///
+    /// - topics - `["migrate", pool_a: Address, pool_b: Address, from: Address]`
+    /// - data - `[amount: i128, tokens_migrated: i128, backstop_shares_minted: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_a` - The address of the pool the shares are migrated from
+    /// * `pool_b` - The address of the pool the shares are migrated to
+    /// * `from` - The address of the user migrating shares
+    /// * `amount` - The amount of pool a backstop shares being burned
+    /// * `tokens_migrated` - The amount of backstop tokens moved from pool a to pool b
+    /// * `backstop_shares_minted` - The amount of pool b backstop shares minted
+    pub fn migrate(
+        e: &Env,
+        pool_a: Address,
+        pool_b: Address,
+        from: Address,
+        amount: i128,
+        tokens_migrated: i128,
+        backstop_shares_minted: i128,
+    ) {
+        let topics = (Symbol::new(e, "migrate"), pool_a, pool_b, from);
+        e.events()
+            .publish(topics, (amount, tokens_migrated, backstop_shares_minted));
+    }
+
+    /// Emitted when a share token is deployed for a pool's backstop
+    ///
+    /// - topics - `["deploy_share_token", pool_address: Address]`
+    /// - data - `share_token: Address`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `share_token` - The address of the share token
+    pub fn deploy_share_token(e: &Env, pool_address: Address, share_token: Address) {
+        let topics = (Symbol::new(e, "deploy_share_token"), pool_address);
+        e.events().publish(topics, share_token);
+    }
+
     /// Emitted when new emissions are distributed
     /// - topics - `["distribute"]`
     /// - data - `[new_tokens_emitted: i128]`
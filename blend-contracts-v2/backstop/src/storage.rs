@@ -1,5 +1,6 @@
 use soroban_sdk::{
-    contracttype, unwrap::UnwrapOptimized, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+    contracttype, unwrap::UnwrapOptimized, vec, Address, BytesN, Env, IntoVal, Symbol, TryFromVal,
+    Val, Vec,
 };
 
 use crate::backstop::{PoolBalance, UserBalance};
@@ -74,12 +75,16 @@ const DROP_LIST_KEY: &str = "DropList";
 const RZ_EMISSION_INDEX_KEY: &str = "RZEmissionIndex";
 const BACKFILL_EMISSIONS_KEY: &str = "BackfillEmis";
 const BACKFILL_STATUS_KEY: &str = "Backfill";
+const SHARE_TOKEN_HASH_KEY: &str = "ShareHash";
+const Q4W_LOCK_TIME_KEY: &str = "Q4WLock";
+const MAX_Q4W_SIZE_KEY: &str = "MaxQ4W";
+const INSTANT_WITHDRAW_FEE_KEY: &str = "InstantFee";
 
 #[derive(Clone)]
 #[contracttype]
 pub struct PoolUserKey {
-    pool: Address,
-    user: Address,
+    pub pool: Address,
+    pub user: Address,
 }
 
 #[derive(Clone)]
@@ -91,6 +96,7 @@ pub enum BackstopDataKey {
     RzEmisData(Address),
     BEmisData(Address),
     UEmisData(PoolUserKey),
+    ShareTkn(Address),
 }
 
 /****************************
@@ -214,6 +220,79 @@ pub fn set_backstop_token(e: &Env, backstop_token_id: &Address) {
         .set::<Symbol, Address>(&Symbol::new(e, BACKSTOP_TOKEN_KEY), backstop_token_id);
 }
 
+/// Fetch the wasm hash of the backstop share token contract
+pub fn get_share_token_hash(e: &Env) -> BytesN<32> {
+    e.storage()
+        .instance()
+        .get::<Symbol, BytesN<32>>(&Symbol::new(e, SHARE_TOKEN_HASH_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the wasm hash of the backstop share token contract
+///
+/// ### Arguments
+/// * `share_token_hash` - The wasm hash of the backstop share token contract
+pub fn set_share_token_hash(e: &Env, share_token_hash: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, BytesN<32>>(&Symbol::new(e, SHARE_TOKEN_HASH_KEY), share_token_hash);
+}
+
+/// Fetch the time in seconds that a Q4W entry is locked for
+pub fn get_q4w_lock_time(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u64>(&Symbol::new(e, Q4W_LOCK_TIME_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the time in seconds that a Q4W entry is locked for
+///
+/// ### Arguments
+/// * `q4w_lock_time` - The lock time in seconds
+pub fn set_q4w_lock_time(e: &Env, q4w_lock_time: &u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, Q4W_LOCK_TIME_KEY), q4w_lock_time);
+}
+
+/// Fetch the maximum amount of active Q4W entries that a user can have against a single backstop
+pub fn get_max_q4w_size(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u32>(&Symbol::new(e, MAX_Q4W_SIZE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the maximum amount of active Q4W entries that a user can have against a single backstop
+///
+/// ### Arguments
+/// * `max_q4w_size` - The maximum amount of active Q4W entries
+pub fn set_max_q4w_size(e: &Env, max_q4w_size: &u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, MAX_Q4W_SIZE_KEY), max_q4w_size);
+}
+
+/// Fetch the fee taken on instant withdrawals, expressed in 7 decimals
+pub fn get_instant_withdraw_fee(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u32>(&Symbol::new(e, INSTANT_WITHDRAW_FEE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the fee taken on instant withdrawals
+///
+/// ### Arguments
+/// * `instant_withdraw_fee` - The fee, expressed in 7 decimals
+pub fn set_instant_withdraw_fee(e: &Env, instant_withdraw_fee: &u32) {
+    e.storage().instance().set::<Symbol, u32>(
+        &Symbol::new(e, INSTANT_WITHDRAW_FEE_KEY),
+        instant_withdraw_fee,
+    );
+}
+
 /********** User Shares **********/
 
 #[cfg(feature = "certora_vec_q4w")]
@@ -274,6 +353,29 @@ pub fn set_user_balance(e: &Env, pool: &Address, user: &Address, balance: &UserB
         .set::<BackstopDataKey, UserBalance>(&key, balance);
 }
 
+/// Extend the TTL of a user's balance and emissions data for a pool without modifying them
+///
+/// ### Arguments
+/// * `pool` - The pool the balance is associated with
+/// * `user` - The owner of the deposit
+pub fn bump_user_balance(e: &Env, pool: &Address, user: &Address) {
+    let pool_user_key = PoolUserKey {
+        pool: pool.clone(),
+        user: user.clone(),
+    };
+    let keys = [
+        BackstopDataKey::UserBalance(pool_user_key.clone()),
+        BackstopDataKey::UEmisData(pool_user_key),
+    ];
+    for key in keys.iter() {
+        if e.storage().persistent().has(key) {
+            e.storage()
+                .persistent()
+                .extend_ttl(key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+        }
+    }
+}
+
 /********** Pool Balance **********/
 
 /// Fetch the balances for a given pool
@@ -310,6 +412,38 @@ pub fn set_pool_balance(e: &Env, pool: &Address, balance: &PoolBalance) {
         .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/********** Share Token **********/
+
+/// Fetch the share token deployed for a pool's backstop, if any
+///
+/// ### Arguments
+/// * `pool` - The pool the share token is associated with
+pub fn get_share_token(e: &Env, pool: &Address) -> Option<Address> {
+    let key = BackstopDataKey::ShareTkn(pool.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the share token deployed for a pool's backstop
+///
+/// ### Arguments
+/// * `pool` - The pool the share token is associated with
+/// * `share_token` - The address of the share token
+pub fn set_share_token(e: &Env, pool: &Address, share_token: &Address) {
+    let key = BackstopDataKey::ShareTkn(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, Address>(&key, share_token);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /********** Distribution / Reward Zone **********/
 
 /// Get the timestamp of when the next emission cycle begins
@@ -1,11 +1,17 @@
 #![cfg(any(test, feature = "testutils"))]
+#[cfg(test)]
+use crate::dependencies::{
+    AuctionData, PoolConfig, Positions, Reserve, ReserveConfig, ReserveData,
+};
 use crate::{
     backstop::Q4W,
-    dependencies::{CometClient, COMET_WASM},
+    dependencies::{CometClient, COMET_WASM, SHARE_TOKEN_WASM},
     storage::{self},
     BackstopContract,
 };
 
+#[cfg(test)]
+use soroban_sdk::{contract, contractimpl, map, Map, Symbol};
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
     unwrap::UnwrapOptimized,
@@ -31,6 +37,10 @@ pub(crate) fn create_backstop(e: &Env) -> Address {
             Address::generate(e),
             Address::generate(e),
             Vec::<(Address, i128)>::new(e),
+            BytesN::from_array(e, &[0; 32]),
+            17 * 24 * 60 * 60_u64,
+            20_u32,
+            0_0500000_u32,
         ),
     )
 }
@@ -102,6 +112,15 @@ pub(crate) fn create_mock_pool_factory<'a>(
     )
 }
 
+/// Upload the backstop share token wasm and set its hash as the backstop's share token hash
+pub(crate) fn create_share_token_hash(e: &Env, backstop: &Address) -> BytesN<32> {
+    let share_token_hash = e.deployer().upload_contract_wasm(SHARE_TOKEN_WASM);
+    e.as_contract(backstop, || {
+        storage::set_share_token_hash(e, &share_token_hash);
+    });
+    share_token_hash
+}
+
 pub(crate) fn create_emitter<'a>(
     e: &Env,
     backstop: &Address,
@@ -216,6 +235,155 @@ pub(crate) fn create_comet_lp_pool_with_tokens_per_share<'a>(
     (contract_address, client)
 }
 
+/********** Mock Pool **********/
+
+#[cfg(test)]
+#[contract]
+pub(crate) struct MockPool;
+
+#[cfg(test)]
+#[contractimpl]
+impl MockPool {
+    pub fn set_status(e: Env, status: u32) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "Status"), &status);
+    }
+
+    pub fn set_liabilities(e: Env, address: Address, liabilities: Map<u32, i128>) {
+        e.storage().instance().set(&address, &liabilities);
+    }
+
+    pub fn set_auction(e: Env, auction_type: u32, user: Address) {
+        e.storage().instance().set(
+            &(auction_type, user),
+            &AuctionData {
+                bid: map![&e],
+                lot: map![&e],
+                block: e.ledger().sequence(),
+            },
+        );
+    }
+
+    pub fn set_d_supply(e: Env, d_supply: i128) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "DSupply"), &d_supply);
+    }
+
+    pub fn get_config(e: Env) -> PoolConfig {
+        PoolConfig {
+            oracle: e.current_contract_address(),
+            min_collateral: 0,
+            bstop_rate: 0,
+            status: e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "Status"))
+                .unwrap_or(1),
+            max_positions: 4,
+        }
+    }
+
+    pub fn get_market(e: Env) -> (PoolConfig, Vec<Reserve>) {
+        let d_supply: i128 = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "DSupply"))
+            .unwrap_or(0);
+        let reserve = Reserve {
+            asset: e.current_contract_address(),
+            config: ReserveConfig {
+                index: 0,
+                decimals: 7,
+                c_factor: 0_7500000,
+                l_factor: 0_7500000,
+                util: 0_5000000,
+                max_util: 0_9500000,
+                r_base: 0_0100000,
+                r_one: 0_0500000,
+                r_two: 0_5000000,
+                r_three: 1_5000000,
+                reactivity: 0_0000020,
+                collateral_cap: i128::MAX,
+                enabled: true,
+            },
+            data: ReserveData {
+                d_rate: 1_000_000_000_000,
+                b_rate: 1_000_000_000_000,
+                ir_mod: 1_0000000,
+                b_supply: 100_0000000,
+                d_supply,
+                backstop_credit: 0,
+                last_time: e.ledger().timestamp(),
+            },
+            scalar: 1_0000000,
+        };
+        (Self::get_config(e.clone()), vec![&e, reserve])
+    }
+
+    pub fn get_positions(e: Env, address: Address) -> Positions {
+        Positions {
+            liabilities: e.storage().instance().get(&address).unwrap_or(map![&e]),
+            collateral: map![&e],
+            supply: map![&e],
+        }
+    }
+
+    pub fn get_auction(e: Env, auction_type: u32, user: Address) -> AuctionData {
+        e.storage()
+            .instance()
+            .get(&(auction_type, user))
+            .unwrap_optimized()
+    }
+}
+
+/// Create a mock pool that only implements the pool functions used by the backstop
+#[cfg(test)]
+pub(crate) fn create_mock_pool<'a>(e: &Env) -> (Address, MockPoolClient<'a>) {
+    let contract_address = e.register(MockPool {}, ());
+    (
+        contract_address.clone(),
+        MockPoolClient::new(e, &contract_address),
+    )
+}
+
+/********** Mock Share Token **********/
+
+#[cfg(test)]
+#[contract]
+pub(crate) struct MockShareToken;
+
+#[cfg(test)]
+#[contractimpl]
+impl MockShareToken {
+    pub fn backstop_mint(e: Env, to: Address, amount: i128) {
+        let minted = Self::minted(e.clone(), to.clone());
+        e.storage().instance().set(&to, &(minted + amount));
+    }
+
+    pub fn minted(e: Env, to: Address) -> i128 {
+        e.storage().instance().get(&to).unwrap_or(0)
+    }
+}
+
+/// Create a mock share token for a pool that records the shares minted to each address
+#[cfg(test)]
+pub(crate) fn create_mock_share_token<'a>(
+    e: &Env,
+    backstop: &Address,
+    pool: &Address,
+) -> (Address, MockShareTokenClient<'a>) {
+    let contract_address = e.register(MockShareToken {}, ());
+    e.as_contract(backstop, || {
+        storage::set_share_token(e, pool, &contract_address);
+    });
+    (
+        contract_address.clone(),
+        MockShareTokenClient::new(e, &contract_address),
+    )
+}
+
 /********** Comparison Helpers **********/
 
 pub(crate) fn assert_eq_vec_q4w(actual: &Vec<Q4W>, expected: &Vec<Q4W>) {
@@ -25,6 +25,13 @@ pub fn create_interest_auction_data(
     if storage::has_auction(e, &(AuctionType::InterestAuction as u32), &backstop) {
         panic_with_error!(e, PoolError::AuctionInProgress);
     }
+    // backstop credit can only be auctioned off by one auction at a time
+    let bad_debt_lot = if storage::has_auction(e, &(AuctionType::BadDebtAuction as u32), &backstop)
+    {
+        storage::get_auction(e, &(AuctionType::BadDebtAuction as u32), &backstop).lot
+    } else {
+        map![e]
+    };
 
     let mut pool = Pool::load(e);
     if pool.config.max_positions < lot.len() {
@@ -40,6 +47,9 @@ pub fn create_interest_auction_data(
     // validate and create lot auction data
     let mut interest_value = 0; // expressed in the oracle's decimals
     for lot_asset in lot {
+        if bad_debt_lot.contains_key(lot_asset.clone()) {
+            panic_with_error!(e, PoolError::AuctionInProgress);
+        }
         // don't store updated reserve data back to ledger. This will occur on the the auction's fill.
         // `load_reserve` will panic if the reserve does not exist
         let reserve = pool.load_reserve(e, &lot_asset, false);
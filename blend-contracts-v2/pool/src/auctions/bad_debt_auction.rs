@@ -7,6 +7,7 @@ use crate::{
     storage,
 };
 use cast::i128;
+use sep_41_token::TokenClient;
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{map, panic_with_error, Address, Env, Vec};
 
@@ -67,26 +68,62 @@ pub fn create_bad_debt_auction_data(
     // validate and create lot auction data
     let backstop_client = BackstopClient::new(e, &backstop);
     let backstop_token = backstop_client.backstop_token();
-    if lot.len() != 1 || lot.get_unchecked(0) != backstop_token {
-        panic_with_error!(e, PoolError::InvalidLot);
+
+    // use any backstop credit held by the reserves to cover the bad debt before
+    // drawing backstop tokens
+    let mut lot_value = debt_value.fixed_mul_floor(e, &1_2000000, &SCALAR_7);
+    for lot_asset in lot.iter() {
+        if lot_asset == backstop_token {
+            continue;
+        }
+        if auction_data.lot.contains_key(lot_asset.clone()) {
+            panic_with_error!(e, PoolError::InvalidLot);
+        }
+        // backstop credit can only be auctioned off by one auction at a time
+        if storage::has_auction(e, &(AuctionType::InterestAuction as u32), &backstop) {
+            panic_with_error!(e, PoolError::AuctionInProgress);
+        }
+        // don't store updated reserve data back to ledger. This will occur on the the auction's fill.
+        let reserve = pool.load_reserve(e, &lot_asset, false);
+        if reserve.data.backstop_credit <= 0 {
+            panic_with_error!(e, PoolError::InvalidLot);
+        }
+        if lot_value > 0 {
+            let asset_to_base = i128(pool.load_price(e, &reserve.asset));
+            let credit_value =
+                asset_to_base.fixed_mul_floor(e, &reserve.data.backstop_credit, &reserve.scalar);
+            let lot_credit = if credit_value > lot_value {
+                lot_value
+                    .fixed_div_ceil(e, &asset_to_base, &reserve.scalar)
+                    .min(reserve.data.backstop_credit)
+            } else {
+                reserve.data.backstop_credit
+            };
+            lot_value -= credit_value.min(lot_value);
+            auction_data.lot.set(reserve.asset, lot_credit);
+        }
     }
 
-    // get value of backstop_token (BLND-USDC LP token) to base
-    let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
-    let backstop_value_base = pool_backstop_data
-        .usdc
-        .fixed_mul_floor(e, &oracle_scalar, &SCALAR_7) // adjust for oracle scalar
-        * 5; // Since the backstop LP token is an 80/20 split of USDC/BLND, we multiply by 5 to get the value of the BLND portion
-    let backstop_token_to_base =
-        backstop_value_base.fixed_div_floor(e, &pool_backstop_data.tokens, &SCALAR_7);
-
-    // determine lot amount of backstop tokens needed to safely cover bad debt, or post
-    // all backstop tokens if there isn't enough to cover the bad debt
-    let mut lot_amount = debt_value
-        .fixed_mul_floor(e, &1_2000000, &SCALAR_7)
-        .fixed_div_floor(e, &backstop_token_to_base, &SCALAR_7);
-    lot_amount = pool_backstop_data.tokens.min(lot_amount);
-    auction_data.lot.set(backstop_token, lot_amount);
+    // backstop tokens are only required if the backstop credit does not cover the bad debt
+    if lot_value > 0 {
+        if !lot.contains(&backstop_token) {
+            panic_with_error!(e, PoolError::InvalidLot);
+        }
+        // get value of backstop_token (BLND-USDC LP token) to base
+        let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
+        let backstop_value_base = pool_backstop_data
+            .usdc
+            .fixed_mul_floor(e, &oracle_scalar, &SCALAR_7) // adjust for oracle scalar
+            * 5; // Since the backstop LP token is an 80/20 split of USDC/BLND, we multiply by 5 to get the value of the BLND portion
+        let backstop_token_to_base =
+            backstop_value_base.fixed_div_floor(e, &pool_backstop_data.tokens, &SCALAR_7);
+
+        // determine lot amount of backstop tokens needed to safely cover the remaining bad debt,
+        // or post all backstop tokens if there isn't enough to cover the bad debt
+        let mut lot_amount = lot_value.fixed_div_floor(e, &backstop_token_to_base, &SCALAR_7);
+        lot_amount = pool_backstop_data.tokens.min(lot_amount);
+        auction_data.lot.set(backstop_token, lot_amount);
+    }
     auction_data
 }
 
@@ -102,6 +139,7 @@ pub fn fill_bad_debt_auction(
         panic_with_error!(e, PoolError::BadRequest);
     }
     let mut backstop_state = User::load(e, &backstop_address);
+    storage::set_bad_debt_last_fill(e, &e.ledger().sequence());
 
     // bid only contains d_token asset amounts
     backstop_state.rm_positions(e, pool, map![e], auction_data.bid.clone());
@@ -109,13 +147,31 @@ pub fn fill_bad_debt_auction(
 
     let backstop_client = BackstopClient::new(e, &backstop_address);
     let backstop_token_id = backstop_client.backstop_token();
-    let lot_amount = auction_data.lot.get(backstop_token_id).unwrap_or(0);
-    if lot_amount > 0 {
-        backstop_client.draw(
-            &e.current_contract_address(),
-            &lot_amount,
-            &filler_state.address,
-        );
+    for (lot_asset, lot_amount) in auction_data.lot.iter() {
+        if lot_amount <= 0 {
+            continue;
+        }
+        if lot_asset == backstop_token_id {
+            backstop_client.draw(
+                &e.current_contract_address(),
+                &lot_amount,
+                &filler_state.address,
+            );
+        } else {
+            // lot contains underlying tokens, but the backstop credit must be updated on the reserve
+            // a stale lot can't pay out more than the reserve's current backstop credit
+            let mut reserve = pool.load_reserve(e, &lot_asset, true);
+            let lot_credit = lot_amount.min(reserve.data.backstop_credit);
+            reserve.data.backstop_credit -= lot_credit;
+            pool.cache_reserve(reserve);
+            if lot_credit > 0 {
+                TokenClient::new(e, &lot_asset).transfer(
+                    &e.current_contract_address(),
+                    &filler_state.address,
+                    &lot_credit,
+                );
+            }
+        }
     }
 
     // If the backstop still has liabilities and less than 5% of the backstop threshold burn bad debt
@@ -990,7 +1046,7 @@ mod tests {
     }
 
     #[test]
-    fn test_create_bad_debt_auction_oracle_14_decimals() {
+    fn test_create_bad_debt_auction_uses_backstop_credit() {
         let e = Env::default();
         e.mock_all_auths_allowing_non_root_auth();
         e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
@@ -1033,6 +1089,7 @@ mod tests {
         let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
         reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.backstop_credit = 5_0000000;
         reserve_data_0.last_time = 12345;
         reserve_config_0.index = 0;
         testutils::create_reserve(
@@ -1056,19 +1113,6 @@ mod tests {
             &reserve_data_1,
         );
 
-        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
-        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
-        reserve_data_2.b_rate = 1_100_000_000_000;
-        reserve_data_2.last_time = 12345;
-        reserve_config_2.index = 1;
-        testutils::create_reserve(
-            &e,
-            &pool_address,
-            &underlying_2,
-            &reserve_config_2,
-            &reserve_data_2,
-        );
-
         oracle_client.set_data(
             &bombadil,
             &Asset::Other(Symbol::new(&e, "USD")),
@@ -1076,30 +1120,18 @@ mod tests {
                 &e,
                 Asset::Stellar(underlying_0.clone()),
                 Asset::Stellar(underlying_1.clone()),
-                Asset::Stellar(underlying_2),
                 Asset::Stellar(usdc),
             ],
-            &14,
+            &7,
             &300,
         );
-        oracle_client.set_price_stable(&vec![
-            &e,
-            2_0000000_0000000,
-            4_0000000_0000000,
-            100_0000000_0000000,
-            1_0000000_0000000,
-        ]);
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
 
         let positions: Positions = Positions {
             collateral: map![&e],
-            liabilities: map![
-                &e,
-                (reserve_config_0.index, 10_0000000),
-                (reserve_config_1.index, 2_5000000)
-            ],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
             supply: map![&e],
         };
-
         let pool_config = PoolConfig {
             oracle: oracle_id,
             min_collateral: 1_0000000,
@@ -1115,21 +1147,22 @@ mod tests {
                 &e,
                 &backstop_address,
                 &vec![&e, underlying_0.clone(), underlying_1.clone()],
-                &vec![&e, lp_token.clone()],
+                &vec![&e, lp_token.clone(), underlying_0.clone()],
                 100,
             );
 
             assert_eq!(result.block, 51);
-            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.get_unchecked(underlying_0.clone()), 10_0000000);
             assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
             assert_eq!(result.bid.len(), 2);
-            assert_eq!(result.lot.get_unchecked(lp_token), 32_6400000);
-            assert_eq!(result.lot.len(), 1);
+            assert_eq!(result.lot.get_unchecked(underlying_0), 5_0000000);
+            assert_eq!(result.lot.get_unchecked(lp_token), 24_6400000);
+            assert_eq!(result.lot.len(), 2);
         });
     }
 
     #[test]
-    fn test_create_bad_debt_auction_max_balance() {
+    fn test_create_bad_debt_auction_backstop_credit_covers_debt() {
         let e = Env::default();
         e.mock_all_auths_allowing_non_root_auth();
         e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
@@ -1147,15 +1180,15 @@ mod tests {
 
         let bombadil = Address::generate(&e);
         let samwise = Address::generate(&e);
-
         let pool_address = create_pool(&e);
+
         let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
         let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
         let (lp_token, lp_token_client) =
             testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
         let (backstop_address, backstop_client) =
             testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
-        // mint lp tokens - only deposit 32_0000000
+        // mint lp tokens
         blnd_client.mint(&samwise, &500_001_0000000);
         blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
         usdc_client.mint(&samwise, &12_501_0000000);
@@ -1165,13 +1198,14 @@ mod tests {
             &vec![&e, 500_001_0000000, 12_501_0000000],
             &samwise,
         );
-        backstop_client.deposit(&samwise, &pool_address, &32_0000000);
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
 
         let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
 
         let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
         reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.backstop_credit = 30_0000000;
         reserve_data_0.last_time = 12345;
         reserve_config_0.index = 0;
         testutils::create_reserve(
@@ -1195,19 +1229,6 @@ mod tests {
             &reserve_data_1,
         );
 
-        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
-        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
-        reserve_data_2.b_rate = 1_100_000_000_000;
-        reserve_data_2.last_time = 12345;
-        reserve_config_2.index = 1;
-        testutils::create_reserve(
-            &e,
-            &pool_address,
-            &underlying_2,
-            &reserve_config_2,
-            &reserve_data_2,
-        );
-
         oracle_client.set_data(
             &bombadil,
             &Asset::Other(Symbol::new(&e, "USD")),
@@ -1215,24 +1236,18 @@ mod tests {
                 &e,
                 Asset::Stellar(underlying_0.clone()),
                 Asset::Stellar(underlying_1.clone()),
-                Asset::Stellar(underlying_2),
                 Asset::Stellar(usdc),
             ],
             &7,
             &300,
         );
-        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
 
         let positions: Positions = Positions {
             collateral: map![&e],
-            liabilities: map![
-                &e,
-                (reserve_config_0.index, 10_0000000),
-                (reserve_config_1.index, 2_5000000)
-            ],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
             supply: map![&e],
         };
-
         let pool_config = PoolConfig {
             oracle: oracle_id,
             min_collateral: 1_0000000,
@@ -1242,28 +1257,27 @@ mod tests {
         };
         e.as_contract(&pool_address, || {
             storage::set_pool_config(&e, &pool_config);
-
             storage::set_user_positions(&e, &backstop_address, &positions);
 
             let result = create_bad_debt_auction_data(
                 &e,
                 &backstop_address,
                 &vec![&e, underlying_0.clone(), underlying_1.clone()],
-                &vec![&e, lp_token.clone()],
+                &vec![&e, lp_token.clone(), underlying_0.clone()],
                 100,
             );
 
             assert_eq!(result.block, 51);
-            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.get_unchecked(underlying_0.clone()), 10_0000000);
             assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
             assert_eq!(result.bid.len(), 2);
-            assert_eq!(result.lot.get_unchecked(lp_token), 32_0000000);
+            assert_eq!(result.lot.get_unchecked(underlying_0), 20_4000000);
             assert_eq!(result.lot.len(), 1);
         });
     }
 
     #[test]
-    fn test_create_bad_debt_auction_applies_interest() {
+    fn test_create_bad_debt_auction_backstop_credit_covers_debt_no_backstop_token() {
         let e = Env::default();
         e.mock_all_auths_allowing_non_root_auth();
         e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
@@ -1271,7 +1285,7 @@ mod tests {
         e.ledger().set(LedgerInfo {
             timestamp: 12345,
             protocol_version: 22,
-            sequence_number: 150,
+            sequence_number: 50,
             network_id: Default::default(),
             base_reserve: 10,
             min_temp_entry_ttl: 10,
@@ -1306,7 +1320,8 @@ mod tests {
         let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
         reserve_data_0.d_rate = 1_100_000_000_000;
-        reserve_data_0.last_time = 11845;
+        reserve_data_0.backstop_credit = 30_0000000;
+        reserve_data_0.last_time = 12345;
         reserve_config_0.index = 0;
         testutils::create_reserve(
             &e,
@@ -1319,7 +1334,7 @@ mod tests {
         let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
         reserve_data_1.d_rate = 1_200_000_000_000;
-        reserve_data_1.last_time = 11845;
+        reserve_data_1.last_time = 12345;
         reserve_config_1.index = 1;
         testutils::create_reserve(
             &e,
@@ -1329,17 +1344,117 @@ mod tests {
             &reserve_data_1,
         );
 
-        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
-        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
-        reserve_data_2.b_rate = 1_100_000_000_000;
-        reserve_data_2.last_time = 11845;
-        reserve_config_2.index = 1;
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
+            supply: map![&e],
+        };
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            let result = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
+                &vec![&e, underlying_0.clone()],
+                100,
+            );
+
+            assert_eq!(result.bid.len(), 2);
+            assert_eq!(result.lot.get_unchecked(underlying_0), 20_4000000);
+            assert_eq!(result.lot.len(), 1);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1222)")]
+    fn test_create_bad_debt_auction_backstop_credit_short_no_backstop_token() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 50,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.backstop_credit = 5_0000000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
         testutils::create_reserve(
             &e,
             &pool_address,
-            &underlying_2,
-            &reserve_config_2,
-            &reserve_data_2,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
         );
 
         oracle_client.set_data(
@@ -1349,24 +1464,18 @@ mod tests {
                 &e,
                 Asset::Stellar(underlying_0.clone()),
                 Asset::Stellar(underlying_1.clone()),
-                Asset::Stellar(underlying_2),
                 Asset::Stellar(usdc),
             ],
             &7,
             &300,
         );
-        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
 
         let positions: Positions = Positions {
             collateral: map![&e],
-            liabilities: map![
-                &e,
-                (reserve_config_0.index, 10_0000000),
-                (reserve_config_1.index, 2_5000000)
-            ],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
             supply: map![&e],
         };
-
         let pool_config = PoolConfig {
             oracle: oracle_id,
             min_collateral: 1_0000000,
@@ -1376,28 +1485,20 @@ mod tests {
         };
         e.as_contract(&pool_address, || {
             storage::set_pool_config(&e, &pool_config);
-            storage::set_backstop(&e, &backstop_address);
             storage::set_user_positions(&e, &backstop_address, &positions);
 
-            let result = create_bad_debt_auction_data(
+            create_bad_debt_auction_data(
                 &e,
                 &backstop_address,
                 &vec![&e, underlying_0.clone(), underlying_1.clone()],
-                &vec![&e, lp_token.clone()],
+                &vec![&e, underlying_0.clone()],
                 100,
             );
-
-            assert_eq!(result.block, 151);
-            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
-            assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
-            assert_eq!(result.bid.len(), 2);
-            assert_eq!(result.lot.get_unchecked(lp_token), 32_6401624);
-            assert_eq!(result.lot.len(), 1);
         });
     }
 
     #[test]
-    fn test_create_bad_debt_auction_partial() {
+    fn test_create_bad_debt_auction_oracle_14_decimals() {
         let e = Env::default();
         e.mock_all_auths_allowing_non_root_auth();
         e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
@@ -1486,10 +1587,16 @@ mod tests {
                 Asset::Stellar(underlying_2),
                 Asset::Stellar(usdc),
             ],
-            &7,
+            &14,
             &300,
         );
-        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+        oracle_client.set_price_stable(&vec![
+            &e,
+            2_0000000_0000000,
+            4_0000000_0000000,
+            100_0000000_0000000,
+            1_0000000_0000000,
+        ]);
 
         let positions: Positions = Positions {
             collateral: map![&e],
@@ -1515,21 +1622,22 @@ mod tests {
             let result = create_bad_debt_auction_data(
                 &e,
                 &backstop_address,
-                &vec![&e, underlying_0.clone()],
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
                 &vec![&e, lp_token.clone()],
                 100,
             );
 
             assert_eq!(result.block, 51);
             assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
-            assert_eq!(result.bid.len(), 1);
-            assert_eq!(result.lot.get_unchecked(lp_token), 21_1200000);
+            assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
+            assert_eq!(result.bid.len(), 2);
+            assert_eq!(result.lot.get_unchecked(lp_token), 32_6400000);
             assert_eq!(result.lot.len(), 1);
         });
     }
 
     #[test]
-    fn test_fill_bad_debt_auction() {
+    fn test_create_bad_debt_auction_max_balance() {
         let e = Env::default();
         e.mock_all_auths_allowing_non_root_auth();
         e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
@@ -1537,7 +1645,7 @@ mod tests {
         e.ledger().set(LedgerInfo {
             timestamp: 12345,
             protocol_version: 22,
-            sequence_number: 51,
+            sequence_number: 50,
             network_id: Default::default(),
             base_reserve: 10,
             min_temp_entry_ttl: 10,
@@ -1549,14 +1657,13 @@ mod tests {
         let samwise = Address::generate(&e);
 
         let pool_address = create_pool(&e);
-
         let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
         let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
         let (lp_token, lp_token_client) =
             testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
         let (backstop_address, backstop_client) =
             testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
-        // mint lp tokens
+        // mint lp tokens - only deposit 32_0000000
         blnd_client.mint(&samwise, &500_001_0000000);
         blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
         usdc_client.mint(&samwise, &12_501_0000000);
@@ -1566,7 +1673,9 @@ mod tests {
             &vec![&e, 500_001_0000000, 12_501_0000000],
             &samwise,
         );
-        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+        backstop_client.deposit(&samwise, &pool_address, &32_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
 
         let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
@@ -1606,9 +1715,408 @@ mod tests {
             &reserve_config_2,
             &reserve_data_2,
         );
-        let pool_config = PoolConfig {
-            oracle: Address::generate(&e),
-            min_collateral: 1_0000000,
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(underlying_2),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![
+                &e,
+                (reserve_config_0.index, 10_0000000),
+                (reserve_config_1.index, 2_5000000)
+            ],
+            supply: map![&e],
+        };
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            let result = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
+                &vec![&e, lp_token.clone()],
+                100,
+            );
+
+            assert_eq!(result.block, 51);
+            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
+            assert_eq!(result.bid.len(), 2);
+            assert_eq!(result.lot.get_unchecked(lp_token), 32_0000000);
+            assert_eq!(result.lot.len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_create_bad_debt_auction_applies_interest() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 150,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.last_time = 11845;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 11845;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
+        reserve_data_2.b_rate = 1_100_000_000_000;
+        reserve_data_2.last_time = 11845;
+        reserve_config_2.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_2,
+            &reserve_config_2,
+            &reserve_data_2,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(underlying_2),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![
+                &e,
+                (reserve_config_0.index, 10_0000000),
+                (reserve_config_1.index, 2_5000000)
+            ],
+            supply: map![&e],
+        };
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_backstop(&e, &backstop_address);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            let result = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
+                &vec![&e, lp_token.clone()],
+                100,
+            );
+
+            assert_eq!(result.block, 151);
+            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
+            assert_eq!(result.bid.len(), 2);
+            assert_eq!(result.lot.get_unchecked(lp_token), 32_6401624);
+            assert_eq!(result.lot.len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_create_bad_debt_auction_partial() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 50,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
+        reserve_data_2.b_rate = 1_100_000_000_000;
+        reserve_data_2.last_time = 12345;
+        reserve_config_2.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_2,
+            &reserve_config_2,
+            &reserve_data_2,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(underlying_2),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![
+                &e,
+                (reserve_config_0.index, 10_0000000),
+                (reserve_config_1.index, 2_5000000)
+            ],
+            supply: map![&e],
+        };
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            let result = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone()],
+                &vec![&e, lp_token.clone()],
+                100,
+            );
+
+            assert_eq!(result.block, 51);
+            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.len(), 1);
+            assert_eq!(result.lot.get_unchecked(lp_token), 21_1200000);
+            assert_eq!(result.lot.len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_fill_bad_debt_auction() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 51,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
+        reserve_data_2.b_rate = 1_100_000_000_000;
+        reserve_data_2.last_time = 12345;
+        reserve_config_2.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_2,
+            &reserve_config_2,
+            &reserve_data_2,
+        );
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
             bstop_rate: 0_1000000,
             status: 0,
             max_positions: 4,
@@ -1666,6 +2174,288 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_fill_bad_debt_auction_with_backstop_credit() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 51,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.backstop_credit = 5_0000000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
+            supply: map![&e],
+        };
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+        });
+        let underlying_0_client = TokenClient::new(&e, &underlying_0);
+
+        let mut auction_data = AuctionData {
+            bid: map![
+                &e,
+                (underlying_0.clone(), 10_0000000),
+                (underlying_1, 2_5000000)
+            ],
+            lot: map![
+                &e,
+                (lp_token.clone(), 24_6400000),
+                (underlying_0.clone(), 5_0000000)
+            ],
+            block: 51,
+        };
+
+        e.as_contract(&pool_address, || {
+            storage::set_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop_address,
+                &auction_data,
+            );
+
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state);
+            pool.store_cached_reserves(&e);
+            assert_eq!(
+                lp_token_client.balance(&backstop_address),
+                50_000_0000000 - 24_6400000
+            );
+            assert_eq!(lp_token_client.balance(&samwise), 24_6400000);
+            assert_eq!(underlying_0_client.balance(&samwise), 5_0000000);
+            assert_eq!(storage::get_res_data(&e, &underlying_0).backstop_credit, 0);
+            let samwise_positions = samwise_state.positions;
+            assert_eq!(samwise_positions.liabilities.get(0).unwrap(), 10_0000000);
+            assert_eq!(samwise_positions.liabilities.get(1).unwrap(), 2_5000000);
+            let backstop_positions = storage::get_user_positions(&e, &backstop_address);
+            assert_eq!(backstop_positions.liabilities.len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_fill_bad_debt_auction_stale_backstop_credit_lot() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 51,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.backstop_credit = 5_0000000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![&e, (0, 10_0000000), (1, 2_5000000)],
+            supply: map![&e],
+        };
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+        });
+        let underlying_0_client = TokenClient::new(&e, &underlying_0);
+
+        let mut auction_data = AuctionData {
+            bid: map![
+                &e,
+                (underlying_0.clone(), 10_0000000),
+                (underlying_1, 2_5000000)
+            ],
+            lot: map![
+                &e,
+                (lp_token.clone(), 24_6400000),
+                (underlying_0.clone(), 8_0000000)
+            ],
+            block: 51,
+        };
+
+        e.as_contract(&pool_address, || {
+            storage::set_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop_address,
+                &auction_data,
+            );
+
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state);
+            pool.store_cached_reserves(&e);
+            assert_eq!(
+                lp_token_client.balance(&backstop_address),
+                50_000_0000000 - 24_6400000
+            );
+            assert_eq!(lp_token_client.balance(&samwise), 24_6400000);
+            assert_eq!(underlying_0_client.balance(&samwise), 5_0000000);
+            assert_eq!(storage::get_res_data(&e, &underlying_0).backstop_credit, 0);
+            let samwise_positions = samwise_state.positions;
+            assert_eq!(samwise_positions.liabilities.get(0).unwrap(), 10_0000000);
+            assert_eq!(samwise_positions.liabilities.get(1).unwrap(), 2_5000000);
+            let backstop_positions = storage::get_user_positions(&e, &backstop_address);
+            assert_eq!(backstop_positions.liabilities.len(), 0);
+        });
+    }
+
     #[test]
     fn test_fill_bad_debt_auction_leftover_debt_small_backstop_burns() {
         let e = Env::default();
@@ -12,5 +12,44 @@ pub const SECONDS_PER_YEAR: i128 = 31536000;
 /// Seconds per week
 pub const SECONDS_PER_WEEK: u64 = 604800;
 
+/// The time it takes a reserve being wound down to reach a collateral factor of 0
+pub const RESERVE_WIND_DOWN_PERIOD: u64 = 4 * SECONDS_PER_WEEK;
+
+/// Minimum time left in a reserve token's emission schedule for it to receive new emissions (1 day)
+pub const MIN_EMISSION_SCHEDULE_DURATION: u64 = 24 * 60 * 60;
+
 /// Max amount of reserves that can be added to a pool
 pub const MAX_RESERVES: u32 = 50;
+
+/// Max amount of third-party reward tokens that can be registered for a reserve token
+pub const MAX_REWARD_TOKENS: u32 = 4;
+
+/// Default number of ledgers a bad debt auction can go unfilled before the backstop's
+/// bad debt can be written off (~7 days)
+pub const DEFAULT_WRITE_OFF_DELAY: u32 = 17280 * 7;
+
+/// Minimum number of ledgers a bad debt auction must go unfilled before the backstop's
+/// bad debt can be written off (~1 day)
+pub const MIN_WRITE_OFF_DELAY: u32 = 17280;
+
+/// Default backstop Q4W percentage that puts the pool on-ice (30%)
+#[allow(clippy::zero_prefixed_literal)]
+pub const DEFAULT_ON_ICE_Q4W: u32 = 0_3000000;
+
+/// Default backstop Q4W percentage that freezes the pool (60%)
+#[allow(clippy::zero_prefixed_literal)]
+pub const DEFAULT_FROZEN_Q4W: u32 = 0_6000000;
+
+/// Default backstop Q4W percentage that puts an admin active pool on-ice (50%)
+#[allow(clippy::zero_prefixed_literal)]
+pub const DEFAULT_ADMIN_ON_ICE_Q4W: u32 = 0_5000000;
+
+/// Default backstop Q4W percentage that freezes an admin on-ice pool (75%)
+#[allow(clippy::zero_prefixed_literal)]
+pub const DEFAULT_ADMIN_FROZEN_Q4W: u32 = 0_7500000;
+
+/// Default minimum backstop threshold for the pool to be active (100%)
+pub const DEFAULT_MIN_THRESHOLD: i128 = 1_0000000;
+
+/// Max minimum backstop threshold a status policy can require for the pool to be active (1000%)
+pub const MAX_MIN_THRESHOLD: i128 = 10_0000000;
@@ -3,10 +3,13 @@ use crate::{
     emissions::{self, ReserveEmissionMetadata},
     events::PoolEvents,
     pool::{self, FlashLoan, Positions, Request, Reserve},
-    storage::{self, ReserveConfig},
-    PoolConfig, ReserveEmissionData, UserEmissionData,
+    storage::{self, PendingAdmin, QueuedReserveInit, ReserveConfig},
+    PoolConfig, PoolError, PoolRole, PoolStatus, ReserveEmissionData, ReserveEmissionSchedule,
+    ReservePause, ReserveWindDown, StatusPolicy, UserEmissionData,
+};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, Map, String, Vec,
 };
-use soroban_sdk::{contract, contractclient, contractimpl, Address, Env, String, Vec};
 
 /// ### Pool
 ///
@@ -25,7 +28,66 @@ pub trait Pool {
     /// If the caller is not the admin
     fn set_admin(e: Env, new_admin: Address);
 
-    /// (Admin only) Update the pool
+    /// (Admin only) Propose a new address as the admin of this pool. The proposed admin
+    /// must call `accept_admin` to take over the admin role.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The proposed admin address
+    /// * `expiration` - The timestamp the proposal expires at, or None if it does not expire
+    ///
+    /// ### Panics
+    /// If the caller is not the admin or the expiration has already passed
+    fn propose_admin(e: Env, new_admin: Address, expiration: Option<u64>);
+
+    /// (Admin only) Cancel the proposed admin
+    ///
+    /// ### Panics
+    /// If the caller is not the admin or no admin is proposed
+    fn cancel_propose_admin(e: Env);
+
+    /// (Proposed admin only) Accept the admin role of this pool
+    ///
+    /// ### Panics
+    /// If the caller is not the proposed admin, no admin is proposed, or the proposal has expired
+    fn accept_admin(e: Env);
+
+    /// Fetch the proposed admin of the pool, if any
+    fn get_pending_admin(e: Env) -> Option<PendingAdmin>;
+
+    /// (Admin only) Grant a role to an address, replacing any address currently granted the role.
+    ///
+    /// The admin is the owner of the pool, and is the only address that can assign roles.
+    /// Roles that have not been granted are held by the admin.
+    /// * RiskManager - can queue or cancel pool updates and reserve changes, and wind down reserves
+    /// * EmissionsManager - can set the pool's emission configuration
+    /// * Guardian - can pause reserves and admin freeze the pool
+    ///
+    /// ### Arguments
+    /// * `role` - The role to grant
+    /// * `address` - The address to grant the role to
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn grant_role(e: Env, role: PoolRole, address: Address);
+
+    /// (Admin only) Revoke a role, returning it to the admin
+    ///
+    /// The admin is the owner of the pool, and is the only address that can revoke roles.
+    ///
+    /// ### Arguments
+    /// * `role` - The role to revoke
+    ///
+    /// ### Panics
+    /// If the caller is not the admin or the role has not been granted
+    fn revoke_role(e: Env, role: PoolRole);
+
+    /// Fetch the address that holds a role
+    ///
+    /// ### Arguments
+    /// * `role` - The role to fetch
+    fn get_role(e: Env, role: PoolRole) -> Address;
+
+    /// (Risk manager only) Queues an update to the pool
     ///
     /// ### Arguments
     /// * `backstop_take_rate` - The new take rate for the backstop (7 decimals)
@@ -34,26 +96,45 @@ pub trait Pool {
     ///                      in the oracles base asset decimals
     ///
     /// ### Panics
-    /// If the caller is not the admin
-    fn update_pool(e: Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128);
+    /// If the caller is not the risk manager, an update is already queued, or the update is invalid
+    fn queue_update_pool(e: Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128);
+
+    /// (Risk manager only) Cancels the queued update to the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the risk manager
+    fn cancel_update_pool(e: Env);
 
-    /// (Admin only) Queues setting data for a reserve in the pool
+    /// Executes the queued update to the pool
+    ///
+    /// Returns the updated pool configuration
+    ///
+    /// ### Panics
+    /// If no update is queued, the update is not unlocked, or the update is invalid
+    fn update_pool(e: Env) -> PoolConfig;
+
+    /// (Risk manager only) Queues setting data for a reserve in the pool
+    ///
+    /// Changes to an existing reserve that only reduce risk are applied immediately. A change
+    /// reduces risk if it only lowers the collateral factor, lowers the collateral cap,
+    /// or disables the reserve.
     ///
     /// ### Arguments
     /// * `asset` - The underlying asset to add as a reserve
     /// * `config` - The ReserveConfig for the reserve
     ///
     /// ### Panics
-    /// If the caller is not the admin
+    /// If the caller is not the risk manager, the asset is a registered reward token,
+    /// or too many new assets are already queued
     fn queue_set_reserve(e: Env, asset: Address, metadata: ReserveConfig);
 
-    /// (Admin only) Cancels the queued set of a reserve in the pool
+    /// (Risk manager only) Cancels the queued set of a reserve in the pool
     ///
     /// ### Arguments
     /// * `asset` - The underlying asset to add as a reserve
     ///
     /// ### Panics
-    /// If the caller is not the admin or the reserve is not queued for initialization
+    /// If the caller is not the risk manager or the reserve is not queued for initialization
     fn cancel_set_reserve(e: Env, asset: Address);
 
     /// (Admin only) Executes the queued set of a reserve in the pool
@@ -67,6 +148,91 @@ pub trait Pool {
     /// or has invalid metadata
     fn set_reserve(e: Env, asset: Address) -> u32;
 
+    /// Fetch the queued set of a reserve, if one is queued
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn get_queued_reserve(e: Env, asset: Address) -> Option<QueuedReserveInit>;
+
+    /// Fetch all queued reserve sets, for both existing reserves and new assets
+    ///
+    /// Returns a map of the underlying asset to the queued reserve set
+    fn get_all_queued_reserves(e: Env) -> Map<Address, QueuedReserveInit>;
+
+    /// (Guardian only) Set the pause switches for a reserve. Takes effect immediately.
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    /// * `pause` - The pause switches for the reserve
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian or the reserve does not exist
+    fn set_reserve_pause(e: Env, asset: Address, pause: ReservePause);
+
+    /// Fetch the pause switches for a reserve
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn get_reserve_pause(e: Env, asset: Address) -> ReservePause;
+
+    /// (Risk manager only) Start winding down a reserve. Takes effect immediately.
+    ///
+    /// A reserve being wound down can't be supplied or borrowed, and its collateral factor
+    /// ramps to 0 over the wind-down period so positions can migrate. A wind-down can't be
+    /// cancelled once started.
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    ///
+    /// ### Panics
+    /// If the caller is not the risk manager, the reserve does not exist,
+    /// or the reserve is already being wound down
+    fn wind_down_reserve(e: Env, asset: Address);
+
+    /// (Risk manager only) Retire a reserve that has been wound down. Retired reserves are
+    /// ignored when calculating positions and distributing emissions.
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    ///
+    /// ### Panics
+    /// If the caller is not the risk manager, the reserve is not being wound down,
+    /// the wind-down period has not passed, or the reserve has outstanding supply, liabilities
+    /// or backstop credit
+    fn retire_reserve(e: Env, asset: Address);
+
+    /// Fetch the wind-down of a reserve, if it is being wound down
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn get_reserve_wind_down(e: Env, asset: Address) -> Option<ReserveWindDown>;
+
+    /// (Admin only) Queues setting the status policy of the pool
+    ///
+    /// ### Arguments
+    /// * `policy` - The new StatusPolicy for the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the admin, a policy is already queued, or the policy is invalid
+    fn queue_set_status_policy(e: Env, policy: StatusPolicy);
+
+    /// (Admin only) Cancels the queued set of the status policy
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn cancel_set_status_policy(e: Env);
+
+    /// Executes the queued set of the status policy
+    ///
+    /// Permissionless, allowing anyone to apply the queued policy once it is unlocked
+    ///
+    /// ### Panics
+    /// If the status policy is not queued or is not unlocked
+    fn set_status_policy(e: Env) -> StatusPolicy;
+
+    /// Fetch the pool's status policy
+    fn get_status_policy(e: Env) -> StatusPolicy;
+
     /// Fetch the pool configuration
     fn get_config(e: Env) -> PoolConfig;
 
@@ -93,6 +259,20 @@ pub trait Pool {
     /// * `address` - The address to fetch positions for
     fn get_positions(e: Env, address: Address) -> Positions;
 
+    /// Extend the TTL of a user's positions and emissions data without modifying them
+    ///
+    /// Permissionless, allowing keepers to keep a user's entries from being archived
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    fn bump_user(e: Env, user: Address);
+
+    /// Extend the TTL of the reserve list, reserve data, and reserve emissions data
+    /// without modifying them
+    ///
+    /// Permissionless, allowing keepers to keep the pool's shared entries from being archived
+    fn bump_reserves(e: Env);
+
     /// Submit a set of requests to the pool where 'from' takes on the position, 'sender' sends any
     /// required tokens to the pool and 'to' receives any tokens sent from the pool.
     ///
@@ -171,7 +351,29 @@ pub trait Pool {
     /// If the user has collateral posted
     fn bad_debt(e: Env, user: Address);
 
-    /// Update the pool status based on the backstop state - backstop triggered status' are odd numbers
+    /// Write off the backstop's bad debt once the bad debt auction has gone unfilled for the
+    /// write-off delay. The backstop's remaining liabilities are defaulted and the loss is
+    /// socialized to the suppliers of each reserve. If the pool is active, it is moved to on-ice.
+    ///
+    /// Returns a map of the reserve assets to the amount of d_tokens written off
+    ///
+    /// ### Panics
+    /// If no bad debt auction exists, or the write-off delay has not passed since the
+    /// auction started or was last filled
+    fn write_off_bad_debt(e: Env) -> Map<Address, i128>;
+
+    /// (Admin only) Set the number of ledgers a bad debt auction can go unfilled before
+    /// the backstop's bad debt can be written off
+    ///
+    /// ### Arguments
+    /// * `ledgers` - The write-off delay in ledgers
+    ///
+    /// ### Panics
+    /// If the caller is not the admin or the delay is less than the minimum
+    fn set_write_off_delay(e: Env, ledgers: u32);
+
+    /// Update the pool status based on the backstop state - backstop triggered status' are odd numbers.
+    /// The Q4W limits and minimum threshold below are the defaults, and can be changed via the status policy.
     /// * 1 = backstop active - if the minimum backstop deposit has been reached
     ///                and 30% of backstop deposits are not queued for withdrawal
     ///                then all pool operations are permitted
@@ -202,6 +404,12 @@ pub trait Pool {
     /// If the specified conditions are not met for the status to be set
     fn set_status(e: Env, pool_status: u32);
 
+    /// (Guardian only) Pool status is changed to 4, "admin frozen"
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    fn freeze(e: Env);
+
     /// Gulps unaccounted for tokens to the backstop credit so they aren't lost. This is most relevant
     /// for rebasing tokens where the token balance of the pool can increase without any corresponding
     /// transfer.
@@ -223,7 +431,7 @@ pub trait Pool {
     /// Returns amount of new tokens emitted
     fn gulp_emissions(e: Env) -> i128;
 
-    /// (Admin only) Set the emission configuration for the pool
+    /// (Emissions manager only) Set the emission configuration for the pool
     ///
     /// Changes will be applied in the next pool `update_emissions`, and affect the next emission cycle
     ///
@@ -231,10 +439,39 @@ pub trait Pool {
     /// * `res_emission_metadata` - A vector of ReserveEmissionMetadata to update metadata to
     ///
     /// ### Panics
-    /// * If the caller is not the admin
+    /// * If the caller is not the emissions manager
     /// * If the sum of ReserveEmissionMetadata shares is greater than 1
     fn set_emissions_config(e: Env, res_emission_metadata: Vec<ReserveEmissionMetadata>);
 
+    /// (Emissions manager only) Set the emissions for a single reserve token without changing
+    /// the rest of the pool's emission configuration
+    ///
+    /// Changes will be applied in the next pool `update_emissions`, and affect the next emission cycle
+    ///
+    /// ### Arguments
+    /// * `res_emission_metadata` - The ReserveEmissionMetadata for the reserve token. A share of 0
+    ///                             removes the reserve token from the emission configuration.
+    /// * `schedule` - The optional start and end time of the reserve token's emissions. Tokens
+    ///                stop receiving new emissions within a day of their schedule ending.
+    ///
+    /// ### Panics
+    /// * If the caller is not the emissions manager
+    /// * If the reserve token is invalid or the schedule has already ended
+    fn set_reserve_emissions(
+        e: Env,
+        res_emission_metadata: ReserveEmissionMetadata,
+        schedule: Option<ReserveEmissionSchedule>,
+    );
+
+    /// Get the emission schedule for a reserve token, if one exists
+    ///
+    /// ### Arguments
+    /// * `reserve_token_id` - The reserve token id
+    fn get_reserve_emissions_schedule(
+        e: Env,
+        reserve_token_id: u32,
+    ) -> Option<ReserveEmissionSchedule>;
+
     /// Claims outstanding emissions for the caller for the given reserve's.
     ///
     /// A reserve token id is a unique identifier for a position in a pool.
@@ -249,6 +486,29 @@ pub trait Pool {
     /// * `to` - The Address to send the claimed tokens to
     fn claim(e: Env, from: Address, reserve_token_ids: Vec<u32>, to: Address) -> i128;
 
+    /// Claims outstanding emissions for the caller for the given reserve's and deposits the
+    /// claimed BLND into the backstop of `pool_address` on behalf of the caller.
+    ///
+    /// The BLND is joined into the backstop token single sided before being deposited.
+    ///
+    /// Returns the number of backstop shares minted to the caller
+    ///
+    /// ### Arguments
+    /// * `from` - The address claiming
+    /// * `reserve_token_ids` - Vector of reserve token ids
+    /// * `pool_address` - The address of the pool whose backstop receives the deposit
+    /// * `min_lp_tokens_out` - The minimum amount of backstop tokens the claimed BLND must join for
+    ///
+    /// ### Panics
+    /// * If less than `min_lp_tokens_out` backstop tokens are received for the claimed BLND
+    fn claim_to_backstop(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        pool_address: Address,
+        min_lp_tokens_out: i128,
+    ) -> i128;
+
     /// Get the emissions data for a reserve token
     ///
     /// A reserve token id is a unique identifier for a position in a pool.
@@ -259,6 +519,92 @@ pub trait Pool {
     /// * `reserve_token_id` - The reserve token id
     fn get_reserve_emissions(e: Env, reserve_token_id: u32) -> Option<ReserveEmissionData>;
 
+    /// (Emissions manager only) Register a third-party reward token for a reserve token
+    ///
+    /// ### Arguments
+    /// * `reserve_token_id` - The reserve token id the reward token is distributed to
+    /// * `reward_token` - The address of the reward token
+    ///
+    /// ### Panics
+    /// * If the caller is not the emissions manager
+    /// * If the reward token is already registered, is BLND or a reserve of the pool, or
+    ///   the reserve token already has the maximum number of reward tokens
+    fn add_reward_token(e: Env, reserve_token_id: u32, reward_token: Address);
+
+    /// Fund a reward program for a reserve token with a registered reward token. The funder
+    /// deposits enough reward tokens to emit `eps` until `expiration`.
+    ///
+    /// Returns the amount of reward tokens deposited
+    ///
+    /// ### Arguments
+    /// * `from` - The address funding the reward program
+    /// * `reserve_token_id` - The reserve token id the reward token is distributed to
+    /// * `reward_token` - The address of the reward token
+    /// * `eps` - The reward tokens emitted per second (14 decimals)
+    /// * `expiration` - The time the reward program ends
+    ///
+    /// ### Panics
+    /// * If the reward token is not registered for the reserve token
+    /// * If a reward program is already active for the reward token
+    ///
+    /// @dev: Reward tokens the previous program could not distribute, because the reserve token
+    ///       had no supply, are used to fund the new program first
+    fn fund_reward(
+        e: Env,
+        from: Address,
+        reserve_token_id: u32,
+        reward_token: Address,
+        eps: u64,
+        expiration: u64,
+    ) -> i128;
+
+    /// Claims outstanding third-party rewards for the caller for the given reserve's.
+    ///
+    /// Returns the number of tokens claimed for each reward token
+    ///
+    /// ### Arguments
+    /// * `from` - The address claiming
+    /// * `reserve_token_ids` - Vector of reserve token ids
+    /// * `reward_tokens` - Vector of reward tokens to claim
+    /// * `to` - The Address to send the claimed tokens to
+    fn claim_rewards(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        reward_tokens: Vec<Address>,
+        to: Address,
+    ) -> Vec<i128>;
+
+    /// Get the third-party reward tokens registered for a reserve token
+    ///
+    /// ### Arguments
+    /// * `reserve_token_id` - The reserve token id
+    fn get_reward_tokens(e: Env, reserve_token_id: u32) -> Vec<Address>;
+
+    /// Get the reward data for a reserve token and reward token
+    ///
+    /// ### Arguments
+    /// * `reserve_token_id` - The reserve token id
+    /// * `reward_token` - The address of the reward token
+    fn get_reserve_rewards(
+        e: Env,
+        reserve_token_id: u32,
+        reward_token: Address,
+    ) -> Option<ReserveEmissionData>;
+
+    /// Get the reward data for a user
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    /// * `reserve_token_id` - The reserve token id
+    /// * `reward_token` - The address of the reward token
+    fn get_user_rewards(
+        e: Env,
+        user: Address,
+        reserve_token_id: u32,
+        reward_token: Address,
+    ) -> Option<UserEmissionData>;
+
     /// Get the emissions data for a user
     ///
     /// A reserve token id is a unique identifier for a position in a pool.
@@ -355,38 +701,159 @@ impl Pool for PoolContract {
         new_admin.require_auth();
 
         storage::set_admin(&e, &new_admin);
+        storage::del_pending_admin(&e);
 
         PoolEvents::set_admin(&e, admin, new_admin);
     }
 
-    fn update_pool(e: Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128) {
+    fn propose_admin(e: Env, new_admin: Address, expiration: Option<u64>) {
         storage::extend_instance(&e);
         let admin = storage::get_admin(&e);
         admin.require_auth();
 
-        pool::execute_update_pool(&e, backstop_take_rate, max_positions, min_collateral);
+        if let Some(expiration) = expiration {
+            if expiration <= e.ledger().timestamp() {
+                panic_with_error!(&e, PoolError::PendingAdminExpired);
+            }
+        }
+        storage::set_pending_admin(
+            &e,
+            &PendingAdmin {
+                admin: new_admin.clone(),
+                expiration,
+            },
+        );
 
-        PoolEvents::update_pool(&e, admin, backstop_take_rate, max_positions, min_collateral);
+        PoolEvents::propose_admin(&e, admin, new_admin, expiration);
     }
 
-    fn queue_set_reserve(e: Env, asset: Address, metadata: ReserveConfig) {
+    fn cancel_propose_admin(e: Env) {
         storage::extend_instance(&e);
         let admin = storage::get_admin(&e);
         admin.require_auth();
 
-        pool::execute_queue_set_reserve(&e, &asset, &metadata);
+        let pending_admin = storage::get_pending_admin(&e)
+            .unwrap_or_else(|| panic_with_error!(&e, PoolError::BadRequest));
+        storage::del_pending_admin(&e);
 
-        PoolEvents::queue_set_reserve(&e, admin, asset, metadata);
+        PoolEvents::cancel_propose_admin(&e, admin, pending_admin.admin);
     }
 
-    fn cancel_set_reserve(e: Env, asset: Address) {
+    fn accept_admin(e: Env) {
+        storage::extend_instance(&e);
+        let pending_admin = storage::get_pending_admin(&e)
+            .unwrap_or_else(|| panic_with_error!(&e, PoolError::BadRequest));
+        pending_admin.admin.require_auth();
+
+        if let Some(expiration) = pending_admin.expiration {
+            if expiration <= e.ledger().timestamp() {
+                panic_with_error!(&e, PoolError::PendingAdminExpired);
+            }
+        }
+        let admin = storage::get_admin(&e);
+        storage::set_admin(&e, &pending_admin.admin);
+        storage::del_pending_admin(&e);
+
+        PoolEvents::accept_admin(&e, admin, pending_admin.admin);
+    }
+
+    fn get_pending_admin(e: Env) -> Option<PendingAdmin> {
+        storage::get_pending_admin(&e)
+    }
+
+    fn grant_role(e: Env, role: PoolRole, address: Address) {
         storage::extend_instance(&e);
         let admin = storage::get_admin(&e);
         admin.require_auth();
 
+        storage::set_role(&e, &role, &address);
+
+        PoolEvents::grant_role(&e, admin, role, address);
+    }
+
+    fn revoke_role(e: Env, role: PoolRole) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        if !storage::has_role(&e, &role) {
+            panic_with_error!(&e, PoolError::BadRequest);
+        }
+        let address = storage::get_role(&e, &role);
+        storage::del_role(&e, &role);
+
+        PoolEvents::revoke_role(&e, admin, role, address);
+    }
+
+    fn get_role(e: Env, role: PoolRole) -> Address {
+        storage::get_role(&e, &role)
+    }
+
+    fn queue_update_pool(
+        e: Env,
+        backstop_take_rate: u32,
+        max_positions: u32,
+        min_collateral: i128,
+    ) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
+        pool::execute_queue_update_pool(&e, backstop_take_rate, max_positions, min_collateral);
+
+        PoolEvents::queue_update_pool(
+            &e,
+            risk_manager,
+            backstop_take_rate,
+            max_positions,
+            min_collateral,
+        );
+    }
+
+    fn cancel_update_pool(e: Env) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
+        pool::execute_cancel_queued_update_pool(&e);
+
+        PoolEvents::cancel_update_pool(&e, risk_manager);
+    }
+
+    fn update_pool(e: Env) -> PoolConfig {
+        storage::extend_instance(&e);
+        let pool_config = pool::execute_update_pool(&e);
+
+        PoolEvents::update_pool(
+            &e,
+            pool_config.bstop_rate,
+            pool_config.max_positions,
+            pool_config.min_collateral,
+        );
+        pool_config
+    }
+
+    fn queue_set_reserve(e: Env, asset: Address, metadata: ReserveConfig) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
+        let applied = pool::execute_queue_set_reserve(&e, &asset, &metadata);
+
+        PoolEvents::queue_set_reserve(&e, risk_manager, asset.clone(), metadata);
+        if let Some(index) = applied {
+            PoolEvents::set_reserve(&e, asset, index);
+        }
+    }
+
+    fn cancel_set_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
         pool::execute_cancel_queued_set_reserve(&e, &asset);
 
-        PoolEvents::cancel_set_reserve(&e, admin, asset);
+        PoolEvents::cancel_set_reserve(&e, risk_manager, asset);
     }
 
     fn set_reserve(e: Env, asset: Address) -> u32 {
@@ -396,6 +863,88 @@ impl Pool for PoolContract {
         index
     }
 
+    fn get_queued_reserve(e: Env, asset: Address) -> Option<QueuedReserveInit> {
+        if storage::has_queued_reserve_set(&e, &asset) {
+            Some(storage::get_queued_reserve_set(&e, &asset))
+        } else {
+            None
+        }
+    }
+
+    fn get_all_queued_reserves(e: Env) -> Map<Address, QueuedReserveInit> {
+        pool::load_queued_reserves(&e)
+    }
+
+    fn set_reserve_pause(e: Env, asset: Address, pause: ReservePause) {
+        storage::extend_instance(&e);
+        let guardian = storage::get_role(&e, &PoolRole::Guardian);
+        guardian.require_auth();
+
+        pool::execute_set_reserve_pause(&e, &asset, &pause);
+
+        PoolEvents::set_reserve_pause(&e, guardian, asset, pause);
+    }
+
+    fn get_reserve_pause(e: Env, asset: Address) -> ReservePause {
+        storage::get_res_pause(&e, &asset)
+    }
+
+    fn wind_down_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
+        pool::execute_wind_down_reserve(&e, &asset);
+
+        PoolEvents::wind_down_reserve(&e, risk_manager, asset);
+    }
+
+    fn retire_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        let risk_manager = storage::get_role(&e, &PoolRole::RiskManager);
+        risk_manager.require_auth();
+
+        pool::execute_retire_reserve(&e, &asset);
+
+        PoolEvents::retire_reserve(&e, risk_manager, asset);
+    }
+
+    fn get_reserve_wind_down(e: Env, asset: Address) -> Option<ReserveWindDown> {
+        storage::get_res_wind_down(&e, &asset)
+    }
+
+    fn queue_set_status_policy(e: Env, policy: StatusPolicy) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        pool::execute_queue_set_status_policy(&e, &policy);
+
+        PoolEvents::queue_set_status_policy(&e, admin, policy);
+    }
+
+    fn cancel_set_status_policy(e: Env) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        pool::execute_cancel_queued_set_status_policy(&e);
+
+        PoolEvents::cancel_set_status_policy(&e, admin);
+    }
+
+    fn set_status_policy(e: Env) -> StatusPolicy {
+        storage::extend_instance(&e);
+        let policy = pool::execute_set_status_policy(&e);
+
+        PoolEvents::set_status_policy(&e, policy.clone());
+        policy
+    }
+
+    fn get_status_policy(e: Env) -> StatusPolicy {
+        storage::get_status_policy(&e)
+    }
+
     fn get_config(e: Env) -> PoolConfig {
         storage::get_pool_config(&e)
     }
@@ -424,6 +973,16 @@ impl Pool for PoolContract {
         storage::get_user_positions(&e, &address)
     }
 
+    fn bump_user(e: Env, user: Address) {
+        storage::extend_instance(&e);
+        storage::bump_user(&e, &user);
+    }
+
+    fn bump_reserves(e: Env) {
+        storage::extend_instance(&e);
+        storage::bump_reserves(&e);
+    }
+
     fn submit(
         e: Env,
         from: Address,
@@ -472,6 +1031,24 @@ impl Pool for PoolContract {
         pool::transfer_bad_debt_to_backstop(&e, &user);
     }
 
+    fn write_off_bad_debt(e: Env) -> Map<Address, i128> {
+        storage::extend_instance(&e);
+        let written_off = pool::execute_write_off_bad_debt(&e);
+
+        PoolEvents::write_off_bad_debt(&e, written_off.clone());
+        written_off
+    }
+
+    fn set_write_off_delay(e: Env, ledgers: u32) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        pool::execute_set_write_off_delay(&e, ledgers);
+
+        PoolEvents::set_write_off_delay(&e, admin, ledgers);
+    }
+
     fn update_status(e: Env) -> u32 {
         storage::extend_instance(&e);
         let new_status = pool::execute_update_pool_status(&e);
@@ -489,6 +1066,16 @@ impl Pool for PoolContract {
         PoolEvents::set_status_admin(&e, admin, pool_status);
     }
 
+    fn freeze(e: Env) {
+        storage::extend_instance(&e);
+        let guardian = storage::get_role(&e, &PoolRole::Guardian);
+        guardian.require_auth();
+        let pool_status = PoolStatus::AdminFrozen as u32;
+        pool::execute_set_pool_status(&e, pool_status);
+
+        PoolEvents::set_status_admin(&e, guardian, pool_status);
+    }
+
     fn gulp(e: Env, asset: Address) -> i128 {
         storage::extend_instance(&e);
         let token_delta = pool::execute_gulp(&e, &asset);
@@ -508,12 +1095,38 @@ impl Pool for PoolContract {
     }
 
     fn set_emissions_config(e: Env, res_emission_metadata: Vec<ReserveEmissionMetadata>) {
-        let admin = storage::get_admin(&e);
-        admin.require_auth();
+        let emissions_manager = storage::get_role(&e, &PoolRole::EmissionsManager);
+        emissions_manager.require_auth();
 
         emissions::set_pool_emissions(&e, res_emission_metadata);
     }
 
+    fn set_reserve_emissions(
+        e: Env,
+        res_emission_metadata: ReserveEmissionMetadata,
+        schedule: Option<ReserveEmissionSchedule>,
+    ) {
+        let emissions_manager = storage::get_role(&e, &PoolRole::EmissionsManager);
+        emissions_manager.require_auth();
+
+        emissions::set_reserve_emissions(&e, &res_emission_metadata, &schedule);
+
+        PoolEvents::set_reserve_emissions(
+            &e,
+            emissions_manager,
+            res_emission_metadata.res_index * 2 + res_emission_metadata.res_type,
+            res_emission_metadata.share,
+            schedule,
+        );
+    }
+
+    fn get_reserve_emissions_schedule(
+        e: Env,
+        reserve_token_id: u32,
+    ) -> Option<ReserveEmissionSchedule> {
+        storage::get_pool_emis_schedules(&e).get(reserve_token_id)
+    }
+
     fn claim(e: Env, from: Address, reserve_token_ids: Vec<u32>, to: Address) -> i128 {
         storage::extend_instance(&e);
         from.require_auth();
@@ -525,10 +1138,119 @@ impl Pool for PoolContract {
         amount_claimed
     }
 
+    fn claim_to_backstop(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        pool_address: Address,
+        min_lp_tokens_out: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (amount_claimed, backstop_shares) = emissions::execute_claim_to_backstop(
+            &e,
+            &from,
+            &reserve_token_ids,
+            &pool_address,
+            min_lp_tokens_out,
+        );
+
+        PoolEvents::claim(&e, from, reserve_token_ids, amount_claimed);
+
+        backstop_shares
+    }
+
     fn get_reserve_emissions(e: Env, reserve_token_index: u32) -> Option<ReserveEmissionData> {
         storage::get_res_emis_data(&e, &reserve_token_index)
     }
 
+    fn add_reward_token(e: Env, reserve_token_id: u32, reward_token: Address) {
+        let emissions_manager = storage::get_role(&e, &PoolRole::EmissionsManager);
+        emissions_manager.require_auth();
+
+        emissions::execute_add_reward_token(&e, reserve_token_id, &reward_token);
+
+        PoolEvents::add_reward_token(&e, emissions_manager, reserve_token_id, reward_token);
+    }
+
+    fn fund_reward(
+        e: Env,
+        from: Address,
+        reserve_token_id: u32,
+        reward_token: Address,
+        eps: u64,
+        expiration: u64,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let amount = emissions::execute_fund_reward(
+            &e,
+            &from,
+            reserve_token_id,
+            &reward_token,
+            eps,
+            expiration,
+        );
+
+        PoolEvents::fund_reward(
+            &e,
+            from,
+            reserve_token_id,
+            reward_token,
+            eps,
+            expiration,
+            amount,
+        );
+        amount
+    }
+
+    fn claim_rewards(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        reward_tokens: Vec<Address>,
+        to: Address,
+    ) -> Vec<i128> {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let amounts_claimed =
+            emissions::execute_claim_rewards(&e, &from, &reserve_token_ids, &reward_tokens, &to);
+
+        PoolEvents::claim_rewards(
+            &e,
+            from,
+            reserve_token_ids,
+            reward_tokens,
+            amounts_claimed.clone(),
+        );
+
+        amounts_claimed
+    }
+
+    fn get_reward_tokens(e: Env, reserve_token_id: u32) -> Vec<Address> {
+        storage::get_reward_tokens(&e, &reserve_token_id)
+    }
+
+    fn get_reserve_rewards(
+        e: Env,
+        reserve_token_id: u32,
+        reward_token: Address,
+    ) -> Option<ReserveEmissionData> {
+        storage::get_reward_data(&e, &reserve_token_id, &reward_token)
+    }
+
+    fn get_user_rewards(
+        e: Env,
+        user: Address,
+        reserve_token_id: u32,
+        reward_token: Address,
+    ) -> Option<UserEmissionData> {
+        storage::get_user_reward(&e, &user, &reserve_token_id, &reward_token)
+    }
+
     fn get_user_emissions(
         e: Env,
         user: Address,
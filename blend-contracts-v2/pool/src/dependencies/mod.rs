@@ -1,2 +1,5 @@
 mod backstop;
 pub use backstop::{Client as BackstopClient, PoolBackstopData};
+
+mod comet;
+pub use comet::Client as CometClient;
@@ -3,8 +3,10 @@ use sep_41_token::TokenClient;
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{panic_with_error, Address, Env, Vec};
 
+use super::rewards;
 use crate::{
     constants::SCALAR_7,
+    dependencies::{BackstopClient, CometClient},
     errors::PoolError,
     pool::User,
     storage::{self, ReserveEmissionData, UserEmissionData},
@@ -17,36 +19,16 @@ pub fn execute_claim(e: &Env, from: &Address, reserve_token_ids: &Vec<u32>, to:
     let reserve_list = storage::get_res_list(e);
     let mut to_claim = 0;
     for reserve_token_id in reserve_token_ids.clone() {
-        let reserve_index = reserve_token_id / 2;
-        let reserve_addr = reserve_list.get(reserve_index);
-        match reserve_addr {
-            Some(res_address) => {
-                let reserve_config = storage::get_res_config(e, &res_address);
-                let reserve_data = storage::get_res_data(e, &res_address);
-                let (user_balance, supply) = match reserve_token_id % 2 {
-                    0 => (
-                        from_state.get_liabilities(reserve_index),
-                        reserve_data.d_supply,
-                    ),
-                    1 => (
-                        from_state.get_total_supply(reserve_index),
-                        reserve_data.b_supply,
-                    ),
-                    _ => panic_with_error!(e, PoolError::BadRequest),
-                };
-                to_claim += claim_emissions(
-                    e,
-                    reserve_token_id,
-                    supply,
-                    10i128.pow(reserve_config.decimals),
-                    from,
-                    user_balance,
-                );
-            }
-            None => {
-                panic_with_error!(e, PoolError::BadRequest)
-            }
-        }
+        let (supply, supply_scalar, user_balance) =
+            load_reserve_token_balance(e, &reserve_list, &from_state, reserve_token_id);
+        to_claim += claim_emissions(
+            e,
+            reserve_token_id,
+            supply,
+            supply_scalar,
+            from,
+            user_balance,
+        );
     }
 
     if to_claim > 0 {
@@ -62,8 +44,74 @@ pub fn execute_claim(e: &Env, from: &Address, reserve_token_ids: &Vec<u32>, to:
     to_claim
 }
 
-/// Update the emissions information about a reserve token. Must be called before any update
-/// is made to the supply of debtTokens or blendTokens.
+/// Performs a claim against the given "reserve_token_ids" for "from", and deposits the claimed
+/// BLND into the backstop of "pool_address" for "from"
+///
+/// Returns a tuple of (amount_claimed, backstop_shares_minted)
+///
+/// ### Panics
+/// If the claimed BLND joins for less than "min_lp_tokens_out" backstop tokens
+pub fn execute_claim_to_backstop(
+    e: &Env,
+    from: &Address,
+    reserve_token_ids: &Vec<u32>,
+    pool_address: &Address,
+    min_lp_tokens_out: i128,
+) -> (i128, i128) {
+    let claimed = execute_claim(e, from, reserve_token_ids, from);
+    if claimed == 0 {
+        return (0, 0);
+    }
+
+    let backstop_client = BackstopClient::new(e, &storage::get_backstop(e));
+    let lp_token = backstop_client.backstop_token();
+    let lp_tokens_out = CometClient::new(e, &lp_token).dep_tokn_amt_in_get_lp_tokns_out(
+        &storage::get_blnd_token(e),
+        &claimed,
+        &min_lp_tokens_out,
+        from,
+    );
+    let backstop_shares = backstop_client.deposit(from, pool_address, &lp_tokens_out);
+    (claimed, backstop_shares)
+}
+
+/// Load the supply, supply scalar, and user balance of a reserve token
+///
+/// Returns a tuple of (supply, supply_scalar, user_balance)
+///
+/// ### Arguments
+/// * `reserve_list` - The list of reserves in the pool
+/// * `user` - The user whose balance is loaded
+/// * `reserve_token_id` - The reserve token id
+///
+/// ### Panics
+/// If the reserve token id is invalid
+pub(super) fn load_reserve_token_balance(
+    e: &Env,
+    reserve_list: &Vec<Address>,
+    user: &User,
+    reserve_token_id: u32,
+) -> (i128, i128, i128) {
+    let reserve_index = reserve_token_id / 2;
+    match reserve_list.get(reserve_index) {
+        Some(res_address) => {
+            let reserve_config = storage::get_res_config(e, &res_address);
+            let reserve_data = storage::get_res_data(e, &res_address);
+            let (user_balance, supply) = match reserve_token_id % 2 {
+                0 => (user.get_liabilities(reserve_index), reserve_data.d_supply),
+                1 => (user.get_total_supply(reserve_index), reserve_data.b_supply),
+                _ => panic_with_error!(e, PoolError::BadRequest),
+            };
+            (supply, 10i128.pow(reserve_config.decimals), user_balance)
+        }
+        None => {
+            panic_with_error!(e, PoolError::BadRequest)
+        }
+    }
+}
+
+/// Update the emissions information about a reserve token, including any third-party rewards.
+/// Must be called before any update is made to the supply of debtTokens or blendTokens.
 ///
 /// A reserve token id is a unique identifier for a position in a pool.
 /// - For a reserve's dTokens (liabilities), reserve_token_id = reserve_index * 2
@@ -99,6 +147,7 @@ pub fn update_emissions(
             false,
         );
     }
+    rewards::update_rewards(e, res_token_id, supply, supply_scalar, user, balance);
 }
 
 /// Update and claim the emissions for a reserve token.
@@ -157,33 +206,53 @@ pub(super) fn update_emission_data(
 ) -> Option<ReserveEmissionData> {
     match storage::get_res_emis_data(e, &res_token_id) {
         Some(mut res_emission_data) => {
-            if res_emission_data.last_time >= res_emission_data.expiration
-                || e.ledger().timestamp() == res_emission_data.last_time
-                || res_emission_data.eps == 0
-                || supply == 0
-            {
-                return Some(res_emission_data);
+            if accrue_emission_index(e, &mut res_emission_data, supply, supply_scalar) {
+                storage::set_res_emis_data(e, &res_token_id, &res_emission_data);
             }
-
-            let ledger_timestamp = if e.ledger().timestamp() > res_emission_data.expiration {
-                res_emission_data.expiration
-            } else {
-                e.ledger().timestamp()
-            };
-
-            let additional_idx = (i128(ledger_timestamp - res_emission_data.last_time)
-                * i128(res_emission_data.eps))
-            .fixed_div_floor(&e, &supply, &supply_scalar);
-
-            res_emission_data.index += additional_idx;
-            res_emission_data.last_time = ledger_timestamp;
-            storage::set_res_emis_data(e, &res_token_id, &res_emission_data);
             Some(res_emission_data)
         }
         None => return None, // no emission exist, no update is required
     }
 }
 
+/// Accrue the emission index of a reserve token up to the current ledger timestamp, or
+/// the expiration of the emissions if it has passed.
+///
+/// Returns true if the emission data was modified
+///
+/// ### Arguments
+/// * `res_emission_data` - The emission data of the reserve token
+/// * `supply` - The current supply of the reserve token
+/// * `supply_scalar` - The scalar of the reserve token
+pub(super) fn accrue_emission_index(
+    e: &Env,
+    res_emission_data: &mut ReserveEmissionData,
+    supply: i128,
+    supply_scalar: i128,
+) -> bool {
+    if res_emission_data.last_time >= res_emission_data.expiration
+        || e.ledger().timestamp() == res_emission_data.last_time
+        || res_emission_data.eps == 0
+        || supply == 0
+    {
+        return false;
+    }
+
+    let ledger_timestamp = if e.ledger().timestamp() > res_emission_data.expiration {
+        res_emission_data.expiration
+    } else {
+        e.ledger().timestamp()
+    };
+
+    let additional_idx = (i128(ledger_timestamp - res_emission_data.last_time)
+        * i128(res_emission_data.eps))
+    .fixed_div_floor(&e, &supply, &supply_scalar);
+
+    res_emission_data.index += additional_idx;
+    res_emission_data.last_time = ledger_timestamp;
+    true
+}
+
 fn update_user_emissions(
     e: &Env,
     res_emis_data: &ReserveEmissionData,
@@ -193,7 +262,36 @@ fn update_user_emissions(
     balance: i128,
     claim: bool,
 ) -> i128 {
-    if let Some(user_data) = storage::get_user_emissions(e, user, &res_token_id) {
+    let user_data = storage::get_user_emissions(e, user, &res_token_id);
+    match accrue_user_emissions(e, res_emis_data, user_data, supply_scalar, balance, claim) {
+        Some((new_user_data, to_claim)) => {
+            storage::set_user_emissions(e, user, &res_token_id, &new_user_data);
+            to_claim
+        }
+        None => 0,
+    }
+}
+
+/// Accrue the emissions of a user for a reserve token
+///
+/// Returns the new user emission data and the amount of tokens to claim, or None if
+/// the user emission data does not need to be updated
+///
+/// ### Arguments
+/// * `res_emis_data` - The updated emission data of the reserve token
+/// * `user_data` - The user's emission data for the reserve token, if any
+/// * `supply_scalar` - The scalar of the reserve token
+/// * `balance` - The current balance of the user
+/// * `claim` - If the accrued emissions should be claimed
+pub(super) fn accrue_user_emissions(
+    e: &Env,
+    res_emis_data: &ReserveEmissionData,
+    user_data: Option<UserEmissionData>,
+    supply_scalar: i128,
+    balance: i128,
+    claim: bool,
+) -> Option<(UserEmissionData, i128)> {
+    if let Some(user_data) = user_data {
         if user_data.index != res_emis_data.index || claim {
             let mut accrual = user_data.accrued;
             if balance != 0 {
@@ -206,39 +304,25 @@ fn update_user_emissions(
                 );
                 accrual += to_accrue;
             }
-            return set_user_emissions(e, user, res_token_id, res_emis_data.index, accrual, claim);
+            return Some(new_user_emissions(res_emis_data.index, accrual, claim));
         }
-        0
+        None
     } else if balance == 0 {
         // first time the user registered an action with the asset since emissions were added
-        return set_user_emissions(e, user, res_token_id, res_emis_data.index, 0, claim);
+        return Some(new_user_emissions(res_emis_data.index, 0, claim));
     } else {
         // user had tokens before emissions began, they are due any historical emissions
         let to_accrue =
             balance.fixed_mul_floor(e, &res_emis_data.index, &(supply_scalar * SCALAR_7));
-        return set_user_emissions(e, user, res_token_id, res_emis_data.index, to_accrue, claim);
+        return Some(new_user_emissions(res_emis_data.index, to_accrue, claim));
     }
 }
 
-fn set_user_emissions(
-    e: &Env,
-    user: &Address,
-    res_token_id: u32,
-    index: i128,
-    accrued: i128,
-    claim: bool,
-) -> i128 {
+fn new_user_emissions(index: i128, accrued: i128, claim: bool) -> (UserEmissionData, i128) {
     if claim {
-        storage::set_user_emissions(
-            e,
-            user,
-            &res_token_id,
-            &UserEmissionData { index, accrued: 0 },
-        );
-        accrued
+        (UserEmissionData { index, accrued: 0 }, accrued)
     } else {
-        storage::set_user_emissions(e, user, &res_token_id, &UserEmissionData { index, accrued });
-        0
+        (UserEmissionData { index, accrued }, 0)
     }
 }
 
@@ -1,9 +1,9 @@
 use crate::{
-    constants::SCALAR_7,
+    constants::{MIN_EMISSION_SCHEDULE_DURATION, SCALAR_7},
     dependencies::BackstopClient,
     errors::PoolError,
     events::PoolEvents,
-    storage::{self, ReserveConfig, ReserveEmissionData},
+    storage::{self, ReserveConfig, ReserveEmissionData, ReserveEmissionSchedule},
 };
 use cast::{i128, u64};
 use soroban_fixed_point_math::SorobanFixedPoint;
@@ -25,7 +25,8 @@ pub struct ReserveEmissionMetadata {
 
 /// Set the pool emissions
 ///
-/// These will not be applied until the next `update_emissions` is run
+/// These will not be applied until the next `update_emissions` is run. Emission schedules
+/// for reserve tokens no longer included in the pool emissions are removed.
 ///
 /// ### Arguments
 /// * `res_emission_metadata` - A vector of `ReserveEmissionMetadata` that details each reserve token's share
@@ -33,25 +34,109 @@ pub struct ReserveEmissionMetadata {
 ///
 /// ### Panics
 /// If any res_emission_metadata is included where share is 0, the reserve index is invalid,
-/// or the reserve type is invalid
+/// the reserve is retired, or the reserve type is invalid
 pub fn set_pool_emissions(e: &Env, res_emission_metadata: Vec<ReserveEmissionMetadata>) {
     let mut pool_emissions: Map<u32, u64> = map![e];
 
-    let reserve_list = storage::get_res_list(e);
     for metadata in res_emission_metadata {
-        let key = metadata.res_index * 2 + metadata.res_type;
-        if metadata.res_type > 1
-            || reserve_list.get(metadata.res_index).is_none()
-            || metadata.share == 0
-        {
+        if metadata.share == 0 {
             panic_with_error!(e, PoolError::BadRequest);
         }
+        let key = require_valid_res_token(e, &metadata);
         pool_emissions.set(key, metadata.share);
     }
 
+    let schedules = storage::get_pool_emis_schedules(e);
+    if !schedules.is_empty() {
+        let mut new_schedules = map![e];
+        for (res_token_id, schedule) in schedules.iter() {
+            if pool_emissions.contains_key(res_token_id) {
+                new_schedules.set(res_token_id, schedule);
+            }
+        }
+        storage::set_pool_emis_schedules(e, &new_schedules);
+    }
+
     storage::set_pool_emissions(e, &pool_emissions);
 }
 
+/// Set the emissions for a single reserve token, leaving the rest of the pool emissions untouched
+///
+/// These will not be applied until the next `update_emissions` is run
+///
+/// ### Arguments
+/// * `metadata` - The `ReserveEmissionMetadata` for the reserve token. A share of 0 removes the
+///                reserve token from the pool emissions.
+/// * `schedule` - The optional emission schedule for the reserve token. If None, the reserve token
+///                receives emissions until it is removed from the pool emissions.
+///
+/// ### Panics
+/// If the reserve index is invalid, the reserve is retired (unless the reserve token is being
+/// removed), the reserve type is invalid, or the schedule is invalid
+pub fn set_reserve_emissions(
+    e: &Env,
+    metadata: &ReserveEmissionMetadata,
+    schedule: &Option<ReserveEmissionSchedule>,
+) {
+    let mut pool_emissions = storage::get_pool_emissions(e);
+    let mut schedules = storage::get_pool_emis_schedules(e);
+
+    if metadata.share == 0 {
+        // retired reserves can still be removed from the pool emissions
+        let key = get_res_token_id(e, metadata);
+        if schedule.is_some() {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+        pool_emissions.remove(key);
+        schedules.remove(key);
+    } else {
+        let key = require_valid_res_token(e, metadata);
+        pool_emissions.set(key, metadata.share);
+        match schedule {
+            Some(schedule) => {
+                if schedule.start_time >= schedule.end_time
+                    || schedule.end_time <= e.ledger().timestamp()
+                {
+                    panic_with_error!(e, PoolError::BadRequest);
+                }
+                schedules.set(key, schedule.clone());
+            }
+            None => {
+                schedules.remove(key);
+            }
+        }
+    }
+
+    storage::set_pool_emissions(e, &pool_emissions);
+    storage::set_pool_emis_schedules(e, &schedules);
+}
+
+/// Validate the reserve token described by the metadata can receive emissions
+///
+/// Returns the reserve token id
+///
+/// ### Panics
+/// If the reserve index is invalid, the reserve is retired, or the reserve type is invalid
+fn require_valid_res_token(e: &Env, metadata: &ReserveEmissionMetadata) -> u32 {
+    let res_token_id = get_res_token_id(e, metadata);
+    let res_address = storage::get_res_list(e).get_unchecked(metadata.res_index);
+    if storage::get_res_wind_down(e, &res_address).is_some_and(|wind_down| wind_down.retired) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    res_token_id
+}
+
+/// Fetch the reserve token id described by the metadata
+///
+/// ### Panics
+/// If the reserve index is invalid or the reserve type is invalid
+fn get_res_token_id(e: &Env, metadata: &ReserveEmissionMetadata) -> u32 {
+    if metadata.res_index >= storage::get_res_list(e).len() || metadata.res_type > 1 {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    metadata.res_index * 2 + metadata.res_type
+}
+
 /// Consume emitted tokens from the backstop and distribute them to reserves
 ///
 /// Returns the number of new tokens distributed for emissions
@@ -73,7 +158,9 @@ fn do_gulp_emissions(e: &Env, new_emissions: i128) {
     }
     let pool_emissions = storage::get_pool_emissions(e);
     let reserve_list = storage::get_res_list(e);
-    let mut pool_emis_enabled: Vec<(ReserveConfig, Address, u32, u64)> = Vec::new(e);
+    let wind_downs = storage::get_res_wind_downs(e);
+    let schedules = storage::get_pool_emis_schedules(e);
+    let mut pool_emis_enabled: Vec<(ReserveConfig, Address, u32, u64, Option<u64>)> = Vec::new(e);
 
     let mut total_share: i128 = 0;
     for (res_token_id, res_eps_share) in pool_emissions.iter() {
@@ -81,17 +168,29 @@ fn do_gulp_emissions(e: &Env, new_emissions: i128) {
         let res_asset_address = reserve_list.get_unchecked(reserve_index);
         let res_config = storage::get_res_config(e, &res_asset_address);
 
-        if res_config.enabled {
+        // scheduled reserve tokens only receive emissions while their schedule is active. Schedules
+        // close to ending are skipped, as emitting over a few seconds would overflow the eps
+        let schedule = schedules.get(res_token_id);
+        let is_scheduled = schedule.as_ref().map_or(true, |schedule| {
+            schedule.start_time <= e.ledger().timestamp()
+                && e.ledger().timestamp() + MIN_EMISSION_SCHEDULE_DURATION <= schedule.end_time
+        });
+
+        // reserves being wound down are disabled
+        if res_config.enabled && is_scheduled && !wind_downs.contains_key(res_asset_address.clone())
+        {
             pool_emis_enabled.push_back((
                 res_config,
                 res_asset_address,
                 res_token_id,
                 res_eps_share,
+                schedule.map(|schedule| schedule.end_time),
             ));
             total_share += i128(res_eps_share);
         }
     }
-    for (res_config, res_asset_address, res_token_id, res_eps_share) in pool_emis_enabled {
+    for (res_config, res_asset_address, res_token_id, res_eps_share, end_time) in pool_emis_enabled
+    {
         let new_reserve_emissions = i128(res_eps_share)
             .fixed_div_floor(e, &total_share, &SCALAR_7)
             .fixed_mul_floor(e, &new_emissions, &SCALAR_7);
@@ -102,6 +201,7 @@ fn do_gulp_emissions(e: &Env, new_emissions: i128) {
             &res_asset_address,
             res_token_id,
             new_reserve_emissions,
+            end_time,
         );
     }
 }
@@ -112,6 +212,7 @@ fn update_reserve_emission_eps(
     asset: &Address,
     res_token_id: u32,
     new_reserve_emissions: i128,
+    end_time: Option<u64>,
 ) {
     let mut tokens_left_to_emit = new_reserve_emissions;
     let reserve_data = storage::get_res_data(e, asset);
@@ -120,7 +221,12 @@ fn update_reserve_emission_eps(
         1 => reserve_data.b_supply,
         _ => panic_with_error!(e, PoolError::BadRequest),
     };
-    let expiration: u64 = e.ledger().timestamp() + 7 * 24 * 60 * 60;
+    // emissions are distributed over a week, or until the end of the reserve token's schedule
+    let expiration: u64 = match end_time {
+        Some(end_time) => end_time.min(e.ledger().timestamp() + 7 * 24 * 60 * 60),
+        None => e.ledger().timestamp() + 7 * 24 * 60 * 60,
+    };
+    let duration = i128(expiration - e.ledger().timestamp());
 
     if let Some(mut emission_data) = distributor::update_emission_data(
         e,
@@ -147,7 +253,7 @@ fn update_reserve_emission_eps(
             tokens_left_to_emit += tokens_since_last_emission;
         }
 
-        let eps = u64(tokens_left_to_emit * SCALAR_7 / (7 * 24 * 60 * 60)).unwrap_optimized();
+        let eps = u64(tokens_left_to_emit * SCALAR_7 / duration).unwrap_optimized();
 
         emission_data.expiration = expiration;
         emission_data.eps = eps;
@@ -155,7 +261,7 @@ fn update_reserve_emission_eps(
         PoolEvents::reserve_emission_update(e, res_token_id, eps, expiration);
     } else {
         // no config or data exists yet - first time this reserve token will get emission
-        let eps = u64(tokens_left_to_emit * SCALAR_7 / (7 * 24 * 60 * 60)).unwrap_optimized();
+        let eps = u64(tokens_left_to_emit * SCALAR_7 / duration).unwrap_optimized();
         storage::set_res_emis_data(
             e,
             &res_token_id,
@@ -172,7 +278,7 @@ fn update_reserve_emission_eps(
 
 #[cfg(test)]
 mod tests {
-    use crate::testutils;
+    use crate::{storage::ReserveWindDown, testutils};
 
     use super::*;
     use soroban_sdk::{
@@ -600,6 +706,59 @@ mod tests {
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_set_pool_emissions_panics_if_reserve_retired() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let res_emission_metadata: Vec<ReserveEmissionMetadata> = vec![
+            &e,
+            ReserveEmissionMetadata {
+                res_index: 0,
+                res_type: 1,
+                share: 0_5000000,
+            },
+            ReserveEmissionMetadata {
+                res_index: 1,
+                res_type: 1,
+                share: 0_5000000,
+            },
+        ];
+
+        e.as_contract(&pool, || {
+            storage::set_res_wind_down(
+                &e,
+                &underlying_1,
+                &ReserveWindDown {
+                    start_time: 1400000000,
+                    retired: true,
+                },
+            );
+
+            set_pool_emissions(&e, res_emission_metadata);
+        });
+    }
+
     #[test]
     fn test_set_pool_emissions_ok_if_under_100() {
         let e = Env::default();
@@ -656,4 +815,385 @@ mod tests {
             assert_eq!(new_pool_emissions.get(6).unwrap_optimized(), 0_6500000);
         });
     }
+
+    #[test]
+    fn test_gulp_emissions_with_schedules() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let new_emissions: i128 = 302_400_0000000;
+        let pool_emissions: Map<u32, u64> = map![
+            &e,
+            (0, 0_5000000), // reserve_0 liability - no schedule
+            (1, 0_2500000), // reserve_0 supply - schedule not started
+            (2, 0_2500000)  // reserve_1 liability - schedule ends in 2 days
+        ];
+        let schedules: Map<u32, ReserveEmissionSchedule> = map![
+            &e,
+            (
+                1,
+                ReserveEmissionSchedule {
+                    start_time: 1500000001,
+                    end_time: 1500000000 + 14 * 24 * 60 * 60,
+                }
+            ),
+            (
+                2,
+                ReserveEmissionSchedule {
+                    start_time: 1499000000,
+                    end_time: 1500000000 + 2 * 24 * 60 * 60,
+                }
+            )
+        ];
+
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 1499900000;
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_emissions(&e, &pool_emissions);
+            storage::set_pool_emis_schedules(&e, &schedules);
+
+            do_gulp_emissions(&e, new_emissions);
+
+            // verify reserve_0 liability emits over a week
+            let r_0_l_data = storage::get_res_emis_data(&e, &0).unwrap_optimized();
+            assert_eq!(r_0_l_data.expiration, 1500000000 + 7 * 24 * 60 * 60);
+            assert_eq!(r_0_l_data.eps, 0_33333330000000);
+
+            // verify reserve_0 supply did not receive emissions before its schedule started
+            assert!(storage::get_res_emis_data(&e, &1).is_none());
+
+            // verify reserve_1 liability emits until the end of its schedule
+            let r_1_l_data = storage::get_res_emis_data(&e, &2).unwrap_optimized();
+            assert_eq!(r_1_l_data.expiration, 1500000000 + 2 * 24 * 60 * 60);
+            assert_eq!(r_1_l_data.eps, 0_58333327500000);
+        });
+    }
+
+    #[test]
+    fn test_gulp_emissions_skips_schedules_ending_soon() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let new_emissions: i128 = 302_400_0000000;
+        let pool_emissions: Map<u32, u64> = map![
+            &e,
+            (0, 0_5000000), // reserve_0 liability - no schedule
+            (1, 0_5000000)  // reserve_0 supply - schedule ends in 5 seconds
+        ];
+        let schedules: Map<u32, ReserveEmissionSchedule> = map![
+            &e,
+            (
+                1,
+                ReserveEmissionSchedule {
+                    start_time: 1499000000,
+                    end_time: 1500000005,
+                }
+            )
+        ];
+
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 1499900000;
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_emissions(&e, &pool_emissions);
+            storage::set_pool_emis_schedules(&e, &schedules);
+
+            do_gulp_emissions(&e, new_emissions);
+
+            // verify reserve_0 liability receives all the emissions
+            let r_0_l_data = storage::get_res_emis_data(&e, &0).unwrap_optimized();
+            assert_eq!(r_0_l_data.expiration, 1500000000 + 7 * 24 * 60 * 60);
+            assert_eq!(r_0_l_data.eps, 0_50000000000000);
+
+            // verify reserve_0 supply did not receive emissions as its schedule is ending
+            assert!(storage::get_res_emis_data(&e, &1).is_none());
+        });
+    }
+
+    #[test]
+    fn test_set_reserve_emissions() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let pool_emissions: Map<u32, u64> = map![&e, (0, 0_7500000)];
+        let schedule = ReserveEmissionSchedule {
+            start_time: 1500000000,
+            end_time: 1500000000 + 14 * 24 * 60 * 60,
+        };
+
+        e.as_contract(&pool, || {
+            storage::set_pool_emissions(&e, &pool_emissions);
+
+            // add a scheduled reserve token
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 1,
+                    res_type: 1,
+                    share: 0_2500000,
+                },
+                &Some(schedule.clone()),
+            );
+            let new_pool_emissions = storage::get_pool_emissions(&e);
+            assert_eq!(new_pool_emissions.len(), 2);
+            assert_eq!(new_pool_emissions.get(0).unwrap_optimized(), 0_7500000);
+            assert_eq!(new_pool_emissions.get(3).unwrap_optimized(), 0_2500000);
+            let schedules = storage::get_pool_emis_schedules(&e);
+            assert_eq!(schedules.len(), 1);
+            assert_eq!(schedules.get(3).unwrap_optimized(), schedule);
+
+            // replacing the config of the pool keeps schedules of included reserve tokens
+            set_pool_emissions(
+                &e,
+                vec![
+                    &e,
+                    ReserveEmissionMetadata {
+                        res_index: 1,
+                        res_type: 1,
+                        share: 0_5000000,
+                    },
+                ],
+            );
+            assert_eq!(storage::get_pool_emissions(&e).len(), 1);
+            assert_eq!(storage::get_pool_emis_schedules(&e).len(), 1);
+
+            // remove the scheduled reserve token
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 1,
+                    res_type: 1,
+                    share: 0,
+                },
+                &None,
+            );
+            assert_eq!(storage::get_pool_emissions(&e).len(), 0);
+            assert_eq!(storage::get_pool_emis_schedules(&e).len(), 0);
+
+            // replacing the config of the pool drops schedules of removed reserve tokens
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 1,
+                    res_type: 1,
+                    share: 0_2500000,
+                },
+                &Some(schedule.clone()),
+            );
+            assert_eq!(storage::get_pool_emis_schedules(&e).len(), 1);
+            set_pool_emissions(
+                &e,
+                vec![
+                    &e,
+                    ReserveEmissionMetadata {
+                        res_index: 0,
+                        res_type: 0,
+                        share: 0_5000000,
+                    },
+                ],
+            );
+            assert_eq!(storage::get_pool_emissions(&e).len(), 1);
+            assert_eq!(storage::get_pool_emis_schedules(&e).len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_set_reserve_emissions_removes_retired_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let pool_emissions: Map<u32, u64> = map![&e, (0, 0_7500000), (3, 0_2500000)];
+        let schedules: Map<u32, ReserveEmissionSchedule> = map![
+            &e,
+            (
+                3,
+                ReserveEmissionSchedule {
+                    start_time: 1500000000,
+                    end_time: 1500000000 + 14 * 24 * 60 * 60,
+                }
+            )
+        ];
+
+        e.as_contract(&pool, || {
+            storage::set_pool_emissions(&e, &pool_emissions);
+            storage::set_pool_emis_schedules(&e, &schedules);
+            storage::set_res_wind_down(
+                &e,
+                &underlying_1,
+                &ReserveWindDown {
+                    start_time: 1400000000,
+                    retired: true,
+                },
+            );
+
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 1,
+                    res_type: 1,
+                    share: 0,
+                },
+                &None,
+            );
+            let new_pool_emissions = storage::get_pool_emissions(&e);
+            assert_eq!(new_pool_emissions.len(), 1);
+            assert_eq!(new_pool_emissions.get(0).unwrap_optimized(), 0_7500000);
+            assert_eq!(storage::get_pool_emis_schedules(&e).len(), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_set_reserve_emissions_panics_if_adding_retired_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            storage::set_res_wind_down(
+                &e,
+                &underlying_0,
+                &ReserveWindDown {
+                    start_time: 1400000000,
+                    retired: true,
+                },
+            );
+
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 0,
+                    res_type: 1,
+                    share: 0_2500000,
+                },
+                &None,
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_set_reserve_emissions_panics_if_schedule_ended() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 20100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            set_reserve_emissions(
+                &e,
+                &ReserveEmissionMetadata {
+                    res_index: 0,
+                    res_type: 1,
+                    share: 0_2500000,
+                },
+                &Some(ReserveEmissionSchedule {
+                    start_time: 1499000000,
+                    end_time: 1500000000,
+                }),
+            );
+        });
+    }
 }
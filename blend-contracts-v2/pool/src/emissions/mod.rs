@@ -1,5 +1,12 @@
 mod manager;
-pub use manager::{gulp_emissions, set_pool_emissions, ReserveEmissionMetadata};
+pub use manager::{
+    gulp_emissions, set_pool_emissions, set_reserve_emissions, ReserveEmissionMetadata,
+};
 
 mod distributor;
-pub use distributor::{execute_claim, update_emissions};
+pub use distributor::{execute_claim, execute_claim_to_backstop, update_emissions};
+
+mod rewards;
+pub use rewards::{
+    execute_add_reward_token, execute_claim_rewards, execute_fund_reward, is_reward_token,
+};
@@ -0,0 +1,817 @@
+use cast::i128;
+use sep_41_token::TokenClient;
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::{
+    constants::{MAX_REWARD_TOKENS, SCALAR_7},
+    errors::PoolError,
+    pool::User,
+    storage::{self, ReserveEmissionData},
+};
+
+use super::distributor;
+
+/// Register a third-party reward token for a reserve token
+///
+/// ### Arguments
+/// * `res_token_id` - The reserve token id the reward token is distributed to
+/// * `reward_token` - The address of the reward token
+///
+/// ### Panics
+/// If the reserve token id is invalid, the reward token is BLND or a reserve of the pool,
+/// the reward token is already registered, or the reserve token has too many reward tokens
+pub fn execute_add_reward_token(e: &Env, res_token_id: u32, reward_token: &Address) {
+    if storage::get_res_list(e).get(res_token_id / 2).is_none()
+        || *reward_token == storage::get_blnd_token(e)
+        || storage::has_res(e, reward_token)
+    {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    let mut reward_tokens = storage::get_reward_tokens(e, &res_token_id);
+    if reward_tokens.contains(reward_token) || reward_tokens.len() >= MAX_REWARD_TOKENS {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    reward_tokens.push_back(reward_token.clone());
+    storage::set_reward_tokens(e, &res_token_id, &reward_tokens);
+}
+
+/// Check if an asset is registered as a reward token for any of the pool's reserve tokens
+///
+/// ### Arguments
+/// * `asset` - The address of the asset
+pub fn is_reward_token(e: &Env, asset: &Address) -> bool {
+    let res_token_count = storage::get_res_list(e).len() * 2;
+    for res_token_id in 0..res_token_count {
+        if storage::get_reward_tokens(e, &res_token_id).contains(asset) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Fund a reward program for a reserve token. The funder deposits enough reward tokens to
+/// emit `eps` until `expiration`. Reward tokens the previous program could not distribute,
+/// because the reserve token had no supply, are used to fund the new program first.
+///
+/// Returns the amount of reward tokens deposited
+///
+/// ### Arguments
+/// * `from` - The address funding the reward program
+/// * `res_token_id` - The reserve token id the reward token is distributed to
+/// * `reward_token` - The address of the reward token
+/// * `eps` - The reward tokens emitted per second (14 decimals)
+/// * `expiration` - The time the reward program ends
+///
+/// ### Panics
+/// If the reward token is not registered for the reserve token, the eps or expiration
+/// are invalid, or a reward program is already active for the reward token
+pub fn execute_fund_reward(
+    e: &Env,
+    from: &Address,
+    res_token_id: u32,
+    reward_token: &Address,
+    eps: u64,
+    expiration: u64,
+) -> i128 {
+    if !storage::get_reward_tokens(e, &res_token_id).contains(reward_token) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let now = e.ledger().timestamp();
+    if eps == 0 || expiration <= now {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    let mut undistributed = 0;
+    let reward_data = match storage::get_reward_data(e, &res_token_id, reward_token) {
+        Some(mut reward_data) => {
+            if reward_data.expiration > now {
+                panic_with_error!(e, PoolError::BadRequest);
+            }
+            // accrue the rest of the previous reward program before starting the new one
+            let res_address = storage::get_res_list(e).get_unchecked(res_token_id / 2);
+            let reserve_config = storage::get_res_config(e, &res_address);
+            let reserve_data = storage::get_res_data(e, &res_address);
+            let supply = match res_token_id % 2 {
+                0 => reserve_data.d_supply,
+                _ => reserve_data.b_supply,
+            };
+            distributor::accrue_emission_index(
+                e,
+                &mut reward_data,
+                supply,
+                10i128.pow(reserve_config.decimals),
+            );
+            // the index is not accrued while there is no supply, so any time left before the
+            // previous expiration was never distributed
+            if reward_data.last_time < reward_data.expiration {
+                undistributed = i128(reward_data.eps).fixed_mul_floor(
+                    e,
+                    &i128(reward_data.expiration - reward_data.last_time),
+                    &SCALAR_7,
+                );
+            }
+            reward_data.eps = eps;
+            reward_data.expiration = expiration;
+            reward_data.last_time = now;
+            reward_data
+        }
+        None => ReserveEmissionData {
+            expiration,
+            eps,
+            index: 0,
+            last_time: now,
+        },
+    };
+
+    // Eps is scaled by 14 decimals
+    let to_emit = i128(eps).fixed_mul_ceil(e, &i128(expiration - now), &SCALAR_7);
+    let amount = (to_emit - undistributed).max(0);
+    if amount > 0 {
+        TokenClient::new(e, reward_token).transfer(from, &e.current_contract_address(), &amount);
+    }
+    storage::set_reward_data(e, &res_token_id, reward_token, &reward_data);
+    amount
+}
+
+/// Performs a claim of the "reward_tokens" against the given "reserve_token_ids" for "from"
+///
+/// Returns the amount claimed of each reward token, in the order of "reward_tokens"
+pub fn execute_claim_rewards(
+    e: &Env,
+    from: &Address,
+    reserve_token_ids: &Vec<u32>,
+    reward_tokens: &Vec<Address>,
+    to: &Address,
+) -> Vec<i128> {
+    let from_state = User::load(e, from);
+    let reserve_list = storage::get_res_list(e);
+    let mut balances: Vec<(u32, i128, i128, i128)> = Vec::new(e);
+    for reserve_token_id in reserve_token_ids.iter() {
+        let (supply, supply_scalar, user_balance) = distributor::load_reserve_token_balance(
+            e,
+            &reserve_list,
+            &from_state,
+            reserve_token_id,
+        );
+        balances.push_back((reserve_token_id, supply, supply_scalar, user_balance));
+    }
+
+    let mut claimed: Vec<i128> = Vec::new(e);
+    for reward_token in reward_tokens.iter() {
+        let mut to_claim = 0;
+        for (reserve_token_id, supply, supply_scalar, user_balance) in balances.iter() {
+            to_claim += update_user_reward(
+                e,
+                reserve_token_id,
+                &reward_token,
+                supply,
+                supply_scalar,
+                from,
+                user_balance,
+                true,
+            );
+        }
+        if to_claim > 0 {
+            TokenClient::new(e, &reward_token).transfer(
+                &e.current_contract_address(),
+                to,
+                &to_claim,
+            );
+        }
+        claimed.push_back(to_claim);
+    }
+    claimed
+}
+
+/// Update the third-party rewards for a reserve token. Must be called before any update
+/// is made to the supply of debtTokens or blendTokens.
+///
+/// ### Arguments
+/// * `res_token_id` - The reserve token id being acted against
+/// * `supply` - The current supply of the reserve token
+/// * `supply_scalar` - The scalar of the reserve token
+/// * `user` - The user performing an action against the reserve
+/// * `balance` - The current balance of the user
+pub(super) fn update_rewards(
+    e: &Env,
+    res_token_id: u32,
+    supply: i128,
+    supply_scalar: i128,
+    user: &Address,
+    balance: i128,
+) {
+    for reward_token in storage::get_reward_tokens(e, &res_token_id).iter() {
+        update_user_reward(
+            e,
+            res_token_id,
+            &reward_token,
+            supply,
+            supply_scalar,
+            user,
+            balance,
+            false,
+        );
+    }
+}
+
+/// Update the reward data of a reserve token and the user's reward data for a reward token
+///
+/// Returns the amount of reward tokens to claim, or zero if 'claim' is false
+#[allow(clippy::too_many_arguments)]
+fn update_user_reward(
+    e: &Env,
+    res_token_id: u32,
+    reward_token: &Address,
+    supply: i128,
+    supply_scalar: i128,
+    user: &Address,
+    balance: i128,
+    claim: bool,
+) -> i128 {
+    match storage::get_reward_data(e, &res_token_id, reward_token) {
+        Some(mut reward_data) => {
+            if distributor::accrue_emission_index(e, &mut reward_data, supply, supply_scalar) {
+                storage::set_reward_data(e, &res_token_id, reward_token, &reward_data);
+            }
+            let user_data = storage::get_user_reward(e, user, &res_token_id, reward_token);
+            match distributor::accrue_user_emissions(
+                e,
+                &reward_data,
+                user_data,
+                supply_scalar,
+                balance,
+                claim,
+            ) {
+                Some((new_user_data, to_claim)) => {
+                    storage::set_user_reward(e, user, &res_token_id, reward_token, &new_user_data);
+                    to_claim
+                }
+                None => 0,
+            }
+        }
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pool::Positions, storage::UserEmissionData, testutils};
+
+    use super::*;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger, LedgerInfo},
+        unwrap::UnwrapOptimized,
+        vec,
+    };
+
+    /********** add_reward_token **********/
+
+    #[test]
+    fn test_add_reward_token() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reward_token_1, _) = testutils::create_token_contract(&e, &bombadil);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token_0);
+            execute_add_reward_token(&e, 1, &reward_token_1);
+            execute_add_reward_token(&e, 0, &reward_token_1);
+
+            assert_eq!(
+                storage::get_reward_tokens(&e, &1),
+                vec![&e, reward_token_0.clone(), reward_token_1.clone()]
+            );
+            assert_eq!(
+                storage::get_reward_tokens(&e, &0),
+                vec![&e, reward_token_1.clone()]
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_add_reward_token_panics_if_registered() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, _) = testutils::create_token_contract(&e, &bombadil);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token);
+            execute_add_reward_token(&e, 1, &reward_token);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_add_reward_token_panics_if_reserve_asset() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &underlying_0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_add_reward_token_panics_if_too_many() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            for _ in 0..MAX_REWARD_TOKENS + 1 {
+                execute_add_reward_token(&e, 0, &Address::generate(&e));
+            }
+        });
+    }
+
+    /********** fund_reward **********/
+
+    #[test]
+    fn test_fund_reward() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, reward_token_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_token_client.mint(&frodo, &2_000_000_0000000);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token);
+
+            // emit 1 token per second for two weeks
+            let amount = execute_fund_reward(
+                &e,
+                &frodo,
+                1,
+                &reward_token,
+                1_00000000000000,
+                1500000000 + 14 * 24 * 60 * 60,
+            );
+            assert_eq!(amount, 1_209_600_0000000);
+            assert_eq!(reward_token_client.balance(&pool), amount);
+            assert_eq!(
+                reward_token_client.balance(&frodo),
+                2_000_000_0000000 - amount
+            );
+
+            let reward_data = storage::get_reward_data(&e, &1, &reward_token).unwrap_optimized();
+            assert_eq!(reward_data.eps, 1_00000000000000);
+            assert_eq!(reward_data.expiration, 1500000000 + 14 * 24 * 60 * 60);
+            assert_eq!(reward_data.index, 0);
+            assert_eq!(reward_data.last_time, 1500000000);
+        });
+    }
+
+    #[test]
+    fn test_fund_reward_after_expiration_accrues_previous_program() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1501000000,
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 100_0000000;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, reward_token_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_token_client.mint(&frodo, &1_000_000_0000000);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token);
+            storage::set_reward_data(
+                &e,
+                &1,
+                &reward_token,
+                &ReserveEmissionData {
+                    expiration: 1500000100,
+                    eps: 1_00000000000000,
+                    index: 0,
+                    last_time: 1500000000,
+                },
+            );
+
+            execute_fund_reward(
+                &e,
+                &frodo,
+                1,
+                &reward_token,
+                2_00000000000000,
+                1501000000 + 1000,
+            );
+
+            // 100 seconds of 1 token per second over 100 bTokens
+            let reward_data = storage::get_reward_data(&e, &1, &reward_token).unwrap_optimized();
+            assert_eq!(reward_data.eps, 2_00000000000000);
+            assert_eq!(reward_data.expiration, 1501000000 + 1000);
+            assert_eq!(reward_data.index, 1_00000000000000);
+            assert_eq!(reward_data.last_time, 1501000000);
+        });
+    }
+
+    #[test]
+    fn test_fund_reward_after_expiration_rolls_over_undistributed() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1501000000,
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, reward_token_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_token_client.mint(&frodo, &1_000_000_0000000);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token);
+            storage::set_reward_data(
+                &e,
+                &1,
+                &reward_token,
+                &ReserveEmissionData {
+                    expiration: 1500000100,
+                    eps: 1_00000000000000,
+                    index: 0,
+                    last_time: 1500000000,
+                },
+            );
+
+            // 100 tokens were not distributed as the reserve token had no supply
+            let amount = execute_fund_reward(
+                &e,
+                &frodo,
+                1,
+                &reward_token,
+                2_00000000000000,
+                1501000000 + 1000,
+            );
+            assert_eq!(amount, 1900_0000000);
+            assert_eq!(reward_token_client.balance(&pool), 1900_0000000);
+
+            let reward_data = storage::get_reward_data(&e, &1, &reward_token).unwrap_optimized();
+            assert_eq!(reward_data.eps, 2_00000000000000);
+            assert_eq!(reward_data.expiration, 1501000000 + 1000);
+            assert_eq!(reward_data.index, 0);
+            assert_eq!(reward_data.last_time, 1501000000);
+
+            // a program smaller than the undistributed rewards requires no deposit
+            storage::set_reward_data(
+                &e,
+                &1,
+                &reward_token,
+                &ReserveEmissionData {
+                    expiration: 1500000100,
+                    eps: 1_00000000000000,
+                    index: 0,
+                    last_time: 1500000000,
+                },
+            );
+            let amount = execute_fund_reward(
+                &e,
+                &frodo,
+                1,
+                &reward_token,
+                0_01000000000000,
+                1501000000 + 1000,
+            );
+            assert_eq!(amount, 0);
+            assert_eq!(reward_token_client.balance(&pool), 1900_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_fund_reward_panics_if_active() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1500000000,
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, reward_token_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_token_client.mint(&frodo, &1_000_000_0000000);
+
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 1, &reward_token);
+            execute_fund_reward(&e, &frodo, 1, &reward_token, 0_10000000000000, 1500001000);
+            execute_fund_reward(&e, &frodo, 1, &reward_token, 0_10000000000000, 1500002000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_fund_reward_panics_if_not_registered() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, reward_token_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_token_client.mint(&frodo, &1_000_000_0000000);
+
+        e.as_contract(&pool, || {
+            execute_fund_reward(&e, &frodo, 1, &reward_token, 0_10000000000000, 1000);
+        });
+    }
+
+    /********** claim_rewards **********/
+
+    #[test]
+    fn test_execute_claim_rewards() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let merry = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1501000000, // 10^6 seconds have passed
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.decimals = 5;
+        reserve_data.b_supply = 100_00000;
+        reserve_data.d_supply = 50_00000;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.decimals = 9;
+        reserve_config.index = 1;
+        reserve_data.b_supply = 100_000_000_000;
+        reserve_data.d_supply = 50_000_000_000;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let (reward_token_0, reward_token_0_client) =
+            testutils::create_token_contract(&e, &bombadil);
+        reward_token_0_client.mint(&pool, &100_000_0000000);
+        let (reward_token_1, reward_token_1_client) =
+            testutils::create_token_contract(&e, &bombadil);
+        reward_token_1_client.mint(&pool, &100_000_0000000);
+
+        let user_positions = Positions {
+            liabilities: map![&e, (0, 2_00000)],
+            collateral: map![&e, (1, 1_000_000_000)],
+            supply: map![&e, (1, 1_000_000_000)],
+        };
+        e.as_contract(&pool, || {
+            storage::set_user_positions(&e, &samwise, &user_positions);
+
+            let res_token_index_0 = 0 * 2 + 0; // d_token for reserve 0
+            let res_token_index_1 = 1 * 2 + 1; // b_token for reserve 1
+            execute_add_reward_token(&e, res_token_index_0, &reward_token_0);
+            execute_add_reward_token(&e, res_token_index_1, &reward_token_0);
+            execute_add_reward_token(&e, res_token_index_1, &reward_token_1);
+
+            storage::set_reward_data(
+                &e,
+                &res_token_index_0,
+                &reward_token_0,
+                &ReserveEmissionData {
+                    expiration: 1600000000,
+                    eps: 0_01000000000000,
+                    index: 23456780000000,
+                    last_time: 1500000000,
+                },
+            );
+            storage::set_user_reward(
+                &e,
+                &samwise,
+                &res_token_index_0,
+                &reward_token_0,
+                &UserEmissionData {
+                    index: 12345670000000,
+                    accrued: 0_1000000,
+                },
+            );
+            let reserve_reward_data_1 = ReserveEmissionData {
+                expiration: 1600000000,
+                eps: 0_01500000000000,
+                index: 13456780000000,
+                last_time: 1500000000,
+            };
+            let user_reward_data_1 = UserEmissionData {
+                index: 12345670000000,
+                accrued: 1_0000000,
+            };
+            storage::set_reward_data(
+                &e,
+                &res_token_index_1,
+                &reward_token_0,
+                &reserve_reward_data_1,
+            );
+            storage::set_user_reward(
+                &e,
+                &samwise,
+                &res_token_index_1,
+                &reward_token_0,
+                &user_reward_data_1,
+            );
+            storage::set_reward_data(
+                &e,
+                &res_token_index_1,
+                &reward_token_1,
+                &reserve_reward_data_1,
+            );
+            storage::set_user_reward(
+                &e,
+                &samwise,
+                &res_token_index_1,
+                &reward_token_1,
+                &user_reward_data_1,
+            );
+
+            let reserve_token_ids: Vec<u32> = vec![&e, res_token_index_0, res_token_index_1];
+            let reward_tokens = vec![&e, reward_token_0.clone(), reward_token_1.clone()];
+            let result =
+                execute_claim_rewards(&e, &samwise, &reserve_token_ids, &reward_tokens, &merry);
+            assert_eq!(result, vec![&e, 400_3222222 + 301_0222222, 301_0222222]);
+
+            let new_reserve_reward_data =
+                storage::get_reward_data(&e, &res_token_index_0, &reward_token_0)
+                    .unwrap_optimized();
+            let new_user_reward_data =
+                storage::get_user_reward(&e, &samwise, &res_token_index_0, &reward_token_0)
+                    .unwrap_optimized();
+            assert_eq!(new_reserve_reward_data.last_time, 1501000000);
+            assert_eq!(new_user_reward_data.index, new_reserve_reward_data.index);
+            assert_eq!(new_user_reward_data.accrued, 0);
+
+            // BLND emissions are not affected
+            assert!(storage::get_res_emis_data(&e, &res_token_index_0).is_none());
+
+            // verify tokens are sent
+            assert_eq!(
+                reward_token_0_client.balance(&merry),
+                400_3222222 + 301_0222222
+            );
+            assert_eq!(reward_token_1_client.balance(&merry), 301_0222222);
+        });
+    }
+
+    /********** update_rewards **********/
+
+    #[test]
+    fn test_update_rewards() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1501000000, // 10^6 seconds have passed
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let supply: i128 = 50_0000000;
+        let user_position: i128 = 2_0000000;
+        e.as_contract(&pool, || {
+            execute_add_reward_token(&e, 0, &reward_token);
+            storage::set_reward_data(
+                &e,
+                &0,
+                &reward_token,
+                &ReserveEmissionData {
+                    expiration: 1600000000,
+                    eps: 0_10000000000000,
+                    index: 0,
+                    last_time: 1500000000,
+                },
+            );
+
+            distributor::update_emissions(&e, 0, supply, SCALAR_7, &samwise, user_position);
+
+            // 10^6 seconds of 0.1 tokens per second over 50 dTokens
+            let new_reward_data =
+                storage::get_reward_data(&e, &0, &reward_token).unwrap_optimized();
+            assert_eq!(new_reward_data.last_time, 1501000000);
+            assert_eq!(new_reward_data.index, 2000_00000000000000);
+
+            // samwise had tokens before the reward program began
+            let new_user_data =
+                storage::get_user_reward(&e, &samwise, &0, &reward_token).unwrap_optimized();
+            assert_eq!(new_user_data.index, 2000_00000000000000);
+            assert_eq!(new_user_data.accrued, 4000_0000000);
+        });
+    }
+}
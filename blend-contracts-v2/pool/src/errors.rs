@@ -52,4 +52,9 @@ pub enum PoolError {
     InvalidLot = 1222,
     ReserveDisabled = 1223,
     MinCollateralNotMet = 1224,
+    WriteOffNotUnlocked = 1225,
+    InvalidStatusTransition = 1226,
+    ReservePaused = 1227,
+    PendingAdminExpired = 1228,
+    ReserveNotWoundDown = 1229,
 }
@@ -1,6 +1,8 @@
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
 
-use crate::{AuctionData, ReserveConfig};
+use crate::{
+    AuctionData, PoolRole, ReserveConfig, ReserveEmissionSchedule, ReservePause, StatusPolicy,
+};
 
 pub struct PoolEvents {}
 
@@ -18,51 +20,192 @@ impl PoolEvents {
         e.events().publish(topics, new_admin);
     }
 
-    /// Emitted when pool parameters are updated
+    /// Emitted when a new admin is proposed for a pool
     ///
-    /// - topics - `["update_pool", admin: Address]`
-    /// - data - `[backstop_take_rate: u32, max_positions: u32, min_collateral: i128]`
+    /// - topics - `["propose_admin", admin: Address]`
+    /// - data - `[new_admin: Address, expiration: Option<u64>]`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    /// * new_admin - The proposed admin of the pool
+    /// * expiration - The timestamp the proposal expires at, if any
+    pub fn propose_admin(e: &Env, admin: Address, new_admin: Address, expiration: Option<u64>) {
+        let topics = (Symbol::new(&e, "propose_admin"), admin);
+        e.events().publish(topics, (new_admin, expiration));
+    }
+
+    /// Emitted when a proposed admin is cancelled
+    ///
+    /// - topics - `["cancel_propose_admin", admin: Address]`
+    /// - data - `new_admin: Address`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    /// * new_admin - The proposed admin that was cancelled
+    pub fn cancel_propose_admin(e: &Env, admin: Address, new_admin: Address) {
+        let topics = (Symbol::new(&e, "cancel_propose_admin"), admin);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a proposed admin accepts the admin role
+    ///
+    /// - topics - `["accept_admin", admin: Address]`
+    /// - data - `new_admin: Address`
+    ///
+    /// ### Arguments
+    /// * admin - The previous admin of the pool
+    /// * new_admin - The new admin of the pool
+    pub fn accept_admin(e: &Env, admin: Address, new_admin: Address) {
+        let topics = (Symbol::new(&e, "accept_admin"), admin);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a role is granted
+    ///
+    /// - topics - `["grant_role", admin: Address, role: PoolRole]`
+    /// - data - `address: Address`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    /// * role - The role granted
+    /// * address - The address granted the role
+    pub fn grant_role(e: &Env, admin: Address, role: PoolRole, address: Address) {
+        let topics = (Symbol::new(&e, "grant_role"), admin, role);
+        e.events().publish(topics, address);
+    }
+
+    /// Emitted when a role is revoked
+    ///
+    /// - topics - `["revoke_role", admin: Address, role: PoolRole]`
+    /// - data - `address: Address`
     ///
     /// ### Arguments
     /// * admin - The current admin of the pool
+    /// * role - The role revoked
+    /// * address - The address the role was revoked from
+    pub fn revoke_role(e: &Env, admin: Address, role: PoolRole, address: Address) {
+        let topics = (Symbol::new(&e, "revoke_role"), admin, role);
+        e.events().publish(topics, address);
+    }
+
+    /// Emitted when the pause switches of a reserve are set
+    ///
+    /// - topics - `["set_reserve_pause", guardian: Address]`
+    /// - data - `[asset: Address, pause: ReservePause]`
+    ///
+    /// ### Arguments
+    /// * guardian - The guardian of the pool
+    /// * asset - The asset of the reserve
+    /// * pause - The new pause switches of the reserve
+    pub fn set_reserve_pause(e: &Env, guardian: Address, asset: Address, pause: ReservePause) {
+        let topics = (Symbol::new(&e, "set_reserve_pause"), guardian);
+        e.events().publish(topics, (asset, pause));
+    }
+
+    /// Emitted when a pool parameter update is queued
+    ///
+    /// - topics - `["queue_update_pool", risk_manager: Address]`
+    /// - data - `[backstop_take_rate: u32, max_positions: u32, min_collateral: i128]`
+    ///
+    /// ### Arguments
+    /// * risk_manager - The risk manager of the pool
     /// * backstop_take_rate - The new backstop take rate
     /// * max_positions - The new maximum number of positions
-    pub fn update_pool(
+    /// * min_collateral - The new minimum collateral
+    pub fn queue_update_pool(
         e: &Env,
-        admin: Address,
+        risk_manager: Address,
         backstop_take_rate: u32,
         max_positions: u32,
         min_collateral: i128,
     ) {
-        let topics = (Symbol::new(&e, "update_pool"), admin);
+        let topics = (Symbol::new(&e, "queue_update_pool"), risk_manager);
+        e.events()
+            .publish(topics, (backstop_take_rate, max_positions, min_collateral));
+    }
+
+    /// Emitted when a queued pool parameter update is cancelled
+    ///
+    /// - topics - `["cancel_update_pool", risk_manager: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * risk_manager - The risk manager of the pool
+    pub fn cancel_update_pool(e: &Env, risk_manager: Address) {
+        let topics = (Symbol::new(&e, "cancel_update_pool"), risk_manager);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a reserve starts being wound down
+    ///
+    /// - topics - `["wind_down_reserve", risk_manager: Address]`
+    /// - data - `asset: Address`
+    ///
+    /// ### Arguments
+    /// * risk_manager - The risk manager of the pool
+    /// * asset - The asset of the reserve
+    pub fn wind_down_reserve(e: &Env, risk_manager: Address, asset: Address) {
+        let topics = (Symbol::new(&e, "wind_down_reserve"), risk_manager);
+        e.events().publish(topics, asset);
+    }
+
+    /// Emitted when a reserve is retired
+    ///
+    /// - topics - `["retire_reserve", risk_manager: Address]`
+    /// - data - `asset: Address`
+    ///
+    /// ### Arguments
+    /// * risk_manager - The risk manager of the pool
+    /// * asset - The asset of the reserve
+    pub fn retire_reserve(e: &Env, risk_manager: Address, asset: Address) {
+        let topics = (Symbol::new(&e, "retire_reserve"), risk_manager);
+        e.events().publish(topics, asset);
+    }
+
+    /// Emitted when pool parameters are updated
+    ///
+    /// - topics - `["update_pool"]`
+    /// - data - `[backstop_take_rate: u32, max_positions: u32, min_collateral: i128]`
+    ///
+    /// ### Arguments
+    /// * backstop_take_rate - The new backstop take rate
+    /// * max_positions - The new maximum number of positions
+    /// * min_collateral - The new minimum collateral
+    pub fn update_pool(e: &Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128) {
+        let topics = (Symbol::new(&e, "update_pool"),);
         e.events()
             .publish(topics, (backstop_take_rate, max_positions, min_collateral));
     }
 
     /// Emitted when a new reserve configuration change is queued
     ///
-    /// - topics - `["queue_set_reserve", admin: Address]`
+    /// - topics - `["queue_set_reserve", risk_manager: Address]`
     /// - data - `[asset: Address, metadata: ReserveMetadata]`
     ///
     /// ### Arguments
-    /// * admin - The current admin of the pool
+    /// * risk_manager - The risk manager of the pool
     /// * asset - The asset to change the reserve configuration of
     /// * metadata - The new reserve configuration
-    pub fn queue_set_reserve(e: &Env, admin: Address, asset: Address, metadata: ReserveConfig) {
-        let topics = (Symbol::new(&e, "queue_set_reserve"), admin);
+    pub fn queue_set_reserve(
+        e: &Env,
+        risk_manager: Address,
+        asset: Address,
+        metadata: ReserveConfig,
+    ) {
+        let topics = (Symbol::new(&e, "queue_set_reserve"), risk_manager);
         e.events().publish(topics, (asset, metadata));
     }
 
     /// Emitted when a queued reserve configuration change is cancelled
     ///
-    /// - topics - `["cancel_set_reserve", admin: Address]`
+    /// - topics - `["cancel_set_reserve", risk_manager: Address]`
     /// - data - `asset: Address`
     ///
     /// ### Arguments
-    /// * admin - The current admin of the pool
+    /// * risk_manager - The risk manager of the pool
     /// * asset - The asset to cancel the reserve configuration change of
-    pub fn cancel_set_reserve(e: &Env, admin: Address, asset: Address) {
-        let topics = (Symbol::new(&e, "cancel_set_reserve"), admin);
+    pub fn cancel_set_reserve(e: &Env, risk_manager: Address, asset: Address) {
+        let topics = (Symbol::new(&e, "cancel_set_reserve"), risk_manager);
         e.events().publish(topics, asset);
     }
 
@@ -79,6 +222,43 @@ impl PoolEvents {
         e.events().publish(topics, (asset, index));
     }
 
+    /// Emitted when a new status policy change is queued
+    ///
+    /// - topics - `["queue_set_status_policy", admin: Address]`
+    /// - data - `policy: StatusPolicy`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    /// * policy - The new status policy
+    pub fn queue_set_status_policy(e: &Env, admin: Address, policy: StatusPolicy) {
+        let topics = (Symbol::new(&e, "queue_set_status_policy"), admin);
+        e.events().publish(topics, policy);
+    }
+
+    /// Emitted when a queued status policy change is cancelled
+    ///
+    /// - topics - `["cancel_set_status_policy", admin: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    pub fn cancel_set_status_policy(e: &Env, admin: Address) {
+        let topics = (Symbol::new(&e, "cancel_set_status_policy"), admin);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a status policy change is set
+    ///
+    /// - topics - `["set_status_policy"]`
+    /// - data - `policy: StatusPolicy`
+    ///
+    /// ### Arguments
+    /// * policy - The new status policy
+    pub fn set_status_policy(e: &Env, policy: StatusPolicy) {
+        let topics = (Symbol::new(&e, "set_status_policy"),);
+        e.events().publish(topics, policy);
+    }
+
     /// Emitted when pool status is updated (non-admin)
     ///
     /// - topics - `["set_status"]`
@@ -91,13 +271,13 @@ impl PoolEvents {
         e.events().publish(topics, new_status);
     }
 
-    /// Emitted when pool status is updated by admin
+    /// Emitted when pool status is updated by the admin or guardian
     ///
     /// - topics - `["set_status", admin: Address]`
     /// - data - `pool_status: PoolStatus`
     ///
     /// ### Arguments
-    /// * admin - The admin setting the pool status
+    /// * admin - The admin or guardian setting the pool status
     /// * pool_status - The new pool status
     pub fn set_status_admin(e: &Env, admin: Address, pool_status: u32) {
         let topics = (Symbol::new(&e, "set_status"), admin);
@@ -118,6 +298,27 @@ impl PoolEvents {
         e.events().publish(topics, (res_token_id, eps, expiration));
     }
 
+    /// Emitted when the emissions for a single reserve token are set
+    ///
+    /// - topics - `["set_reserve_emissions", emissions_manager: Address]`
+    /// - data - `[res_token_id: u32, share: u64, schedule: Option<ReserveEmissionSchedule>]`
+    ///
+    /// ### Arguments
+    /// * emissions_manager - The emissions manager of the pool
+    /// * res_token_id - The reserve token ID
+    /// * share - The reserve token's share of the pool emissions
+    /// * schedule - The emission schedule of the reserve token
+    pub fn set_reserve_emissions(
+        e: &Env,
+        emissions_manager: Address,
+        res_token_id: u32,
+        share: u64,
+        schedule: Option<ReserveEmissionSchedule>,
+    ) {
+        let topics = (Symbol::new(e, "set_reserve_emissions"), emissions_manager);
+        e.events().publish(topics, (res_token_id, share, schedule));
+    }
+
     /// Emitted when emissions are gulped
     ///
     /// - topics - `["gulp_emissions"]`
@@ -145,6 +346,73 @@ impl PoolEvents {
             .publish(topics, (reserve_token_ids, amount_claimed));
     }
 
+    /// Emitted when a third-party reward token is registered for a reserve token
+    ///
+    /// - topics - `["add_reward_token", emissions_manager: Address]`
+    /// - data - `[res_token_id: u32, reward_token: Address]`
+    ///
+    /// ### Arguments
+    /// * emissions_manager - The emissions manager of the pool
+    /// * res_token_id - The reserve token ID
+    /// * reward_token - The reward token
+    pub fn add_reward_token(
+        e: &Env,
+        emissions_manager: Address,
+        res_token_id: u32,
+        reward_token: Address,
+    ) {
+        let topics = (Symbol::new(e, "add_reward_token"), emissions_manager);
+        e.events().publish(topics, (res_token_id, reward_token));
+    }
+
+    /// Emitted when a reward program is funded
+    ///
+    /// - topics - `["fund_reward", from: Address, reward_token: Address]`
+    /// - data - `[res_token_id: u32, eps: u64, expiration: u64, amount: i128]`
+    ///
+    /// ### Arguments
+    /// * from - The address funding the reward program
+    /// * res_token_id - The reserve token ID
+    /// * reward_token - The reward token
+    /// * eps - The reward tokens emitted per second
+    /// * expiration - The time the reward program ends
+    /// * amount - The amount of reward tokens deposited
+    pub fn fund_reward(
+        e: &Env,
+        from: Address,
+        res_token_id: u32,
+        reward_token: Address,
+        eps: u64,
+        expiration: u64,
+        amount: i128,
+    ) {
+        let topics = (Symbol::new(e, "fund_reward"), from, reward_token);
+        e.events()
+            .publish(topics, (res_token_id, eps, expiration, amount));
+    }
+
+    /// Emitted when third-party rewards are claimed
+    ///
+    /// - topics - `["claim_rewards", from: Address]`
+    /// - data - `[reserve_token_ids: Vec<u32>, reward_tokens: Vec<Address>, amounts_claimed: Vec<i128>]`
+    ///
+    /// ### Arguments
+    /// * from - The address claiming the rewards
+    /// * reserve_token_ids - The reserve token IDs claimed
+    /// * reward_tokens - The reward tokens claimed
+    /// * amounts_claimed - The amount claimed of each reward token
+    pub fn claim_rewards(
+        e: &Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        reward_tokens: Vec<Address>,
+        amounts_claimed: Vec<i128>,
+    ) {
+        let topics = (Symbol::new(e, "claim_rewards"), from);
+        e.events()
+            .publish(topics, (reserve_token_ids, reward_tokens, amounts_claimed));
+    }
+
     /// Emitted when bad debt is recorded
     ///
     /// - topics - `["bad_debt", user: Address, asset: Address]`
@@ -172,6 +440,31 @@ impl PoolEvents {
         e.events().publish(topics, d_tokens_burnt);
     }
 
+    /// Emitted when the bad debt write-off delay is updated
+    ///
+    /// - topics - `["set_write_off_delay", admin: Address]`
+    /// - data - `ledgers: u32`
+    ///
+    /// ### Arguments
+    /// * admin - The current admin of the pool
+    /// * ledgers - The new write-off delay in ledgers
+    pub fn set_write_off_delay(e: &Env, admin: Address, ledgers: u32) {
+        let topics = (Symbol::new(e, "set_write_off_delay"), admin);
+        e.events().publish(topics, ledgers);
+    }
+
+    /// Emitted when the backstop's bad debt is written off
+    ///
+    /// - topics - `["write_off_bad_debt"]`
+    /// - data - `written_off: Map<Address, i128>`
+    ///
+    /// ### Arguments
+    /// * written_off - The map of reserve assets to the amount of d_tokens written off
+    pub fn write_off_bad_debt(e: &Env, written_off: Map<Address, i128>) {
+        let topics = (Symbol::new(e, "write_off_bad_debt"),);
+        e.events().publish(topics, written_off);
+    }
+
     /// Emitted when tokens are supplied
     ///
     /// - topics - `["supply", asset: Address, from: Address]`
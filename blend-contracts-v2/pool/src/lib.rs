@@ -22,8 +22,10 @@ pub use auctions::{AuctionData, AuctionType};
 pub use contract::*;
 pub use emissions::ReserveEmissionMetadata;
 pub use errors::PoolError;
-pub use pool::{FlashLoan, Positions, Request, RequestType};
+pub use pool::{FlashLoan, PoolStatus, Positions, Request, RequestType};
 pub use storage::{
-    AuctionKey, PoolConfig, PoolDataKey, PoolEmissionConfig, ReserveConfig, ReserveData,
-    ReserveEmissionData, UserEmissionData, UserReserveKey,
+    AuctionKey, PendingAdmin, PoolConfig, PoolDataKey, PoolEmissionConfig, PoolRole,
+    QueuedReserveInit, ReserveConfig, ReserveData, ReserveEmissionData, ReserveEmissionSchedule,
+    ReservePause, ReserveWindDown, RewardKey, StatusPolicy, UserEmissionData, UserReserveKey,
+    UserRewardKey,
 };
@@ -2,7 +2,7 @@ use soroban_sdk::Map;
 use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
 
 use crate::events::PoolEvents;
-use crate::{auctions, errors::PoolError, validator::require_nonnegative};
+use crate::{auctions, errors::PoolError, storage, validator::require_nonnegative};
 
 use super::pool::Pool;
 use super::User;
@@ -128,6 +128,7 @@ pub fn build_actions_from_request(
         // verify the request is allowed
         require_nonnegative(e, &request.amount);
         pool.require_action_allowed(e, request.request_type);
+        require_reserve_not_paused(e, &request);
         match RequestType::from_u32(e, request.request_type) {
             RequestType::Supply => {
                 let b_tokens_minted = apply_supply(e, &mut actions, pool, from_state, &request);
@@ -265,6 +266,25 @@ pub fn build_actions_from_request(
     actions
 }
 
+/// Require that the request is not paused for the reserve, or panic.
+///
+/// ### Arguments
+/// * `request` - The request being made against the pool
+fn require_reserve_not_paused(e: &Env, request: &Request) {
+    let paused = match RequestType::from_u32(e, request.request_type) {
+        RequestType::Supply => storage::get_res_pause(e, &request.address).supply,
+        RequestType::SupplyCollateral => {
+            let pause = storage::get_res_pause(e, &request.address);
+            pause.supply || pause.collateral
+        }
+        RequestType::Borrow => storage::get_res_pause(e, &request.address).borrow,
+        _ => false,
+    };
+    if paused {
+        panic_with_error!(e, PoolError::ReservePaused);
+    }
+}
+
 /// Apply a "supply" request to the pool
 ///
 /// Appends any necessary actions to the actions list, updates the user and pool's state
@@ -424,7 +444,7 @@ mod tests {
 
     use crate::{
         constants::SCALAR_7,
-        storage::{self, PoolConfig},
+        storage::{self, PoolConfig, ReservePause},
         testutils::{self, create_comet_lp_pool, create_pool},
         AuctionData, AuctionType, Positions,
     };
@@ -2014,4 +2034,173 @@ mod tests {
             build_actions_from_request(&e, &mut pool, &mut user, requests);
         });
     }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1227)")]
+    fn test_build_actions_panic_borrow_paused_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 1,
+        };
+
+        let requests = vec![
+            &e,
+            Request {
+                request_type: RequestType::Borrow as u32,
+                address: underlying.clone(),
+                amount: 20_0000000,
+            },
+        ];
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_pause(
+                &e,
+                &underlying,
+                &ReservePause {
+                    supply: false,
+                    borrow: true,
+                    collateral: false,
+                    flash_loan: false,
+                },
+            );
+            let mut pool = Pool::load(&e);
+            let mut user = User::load(&e, &samwise);
+
+            build_actions_from_request(&e, &mut pool, &mut user, requests);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1227)")]
+    fn test_build_actions_panic_collateral_paused_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 1,
+        };
+
+        let requests = vec![
+            &e,
+            Request {
+                request_type: RequestType::SupplyCollateral as u32,
+                address: underlying.clone(),
+                amount: 20_0000000,
+            },
+        ];
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_pause(
+                &e,
+                &underlying,
+                &ReservePause {
+                    supply: false,
+                    borrow: false,
+                    collateral: true,
+                    flash_loan: false,
+                },
+            );
+            let mut pool = Pool::load(&e);
+            let mut user = User::load(&e, &samwise);
+
+            build_actions_from_request(&e, &mut pool, &mut user, requests);
+        });
+    }
+
+    #[test]
+    fn test_build_actions_allows_repay_and_supply_borrow_paused_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 1,
+        };
+
+        let requests = vec![
+            &e,
+            Request {
+                request_type: RequestType::Supply as u32,
+                address: underlying.clone(),
+                amount: 20_0000000,
+            },
+            Request {
+                request_type: RequestType::Repay as u32,
+                address: underlying.clone(),
+                amount: 20_0000000,
+            },
+        ];
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_pause(
+                &e,
+                &underlying,
+                &ReservePause {
+                    supply: false,
+                    borrow: true,
+                    collateral: false,
+                    flash_loan: false,
+                },
+            );
+            storage::set_user_positions(
+                &e,
+                &samwise,
+                &Positions {
+                    liabilities: map![&e, (0, 10_0000000)],
+                    collateral: map![&e],
+                    supply: map![&e],
+                },
+            );
+            let mut pool = Pool::load(&e);
+            let mut user = User::load(&e, &samwise);
+
+            let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
+            assert_eq!(
+                actions.spender_transfer.get_unchecked(underlying.clone()),
+                40_0000000
+            );
+            assert_eq!(user.positions.supply.len(), 1);
+            assert_eq!(user.positions.liabilities.len(), 0);
+        });
+    }
 }
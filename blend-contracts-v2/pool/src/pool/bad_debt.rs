@@ -1,12 +1,14 @@
-use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_sdk::{map, panic_with_error, Address, Env, Map};
 
 use crate::{
+    constants::MIN_WRITE_OFF_DELAY,
     errors::PoolError,
     events::PoolEvents,
     storage::{self},
+    AuctionType,
 };
 
-use super::{user::User, Pool};
+use super::{status::PoolStatus, user::User, Pool};
 
 /// Transfer bad debt from a user to the backstop. Validates that the user does hold bad debt
 /// and transfers all held d_tokens to the backstop.
@@ -49,9 +51,77 @@ pub fn transfer_bad_debt_to_backstop(e: &Env, user: &Address) {
     new_user_state.store(e);
 }
 
+/// Write off the backstop's bad debt after the bad debt auction has gone unfilled for the
+/// write-off delay. All of the backstop's remaining liabilities are defaulted, and the loss is
+/// socialized to the suppliers of each reserve. If the pool was active, it is moved to on-ice.
+///
+/// Returns a map of the reserve assets to the amount of d_tokens written off
+///
+/// ### Panics
+/// If no bad debt auction exists, the backstop has no liabilities, or the write-off delay
+/// has not passed since the auction started or was last filled
+pub fn execute_write_off_bad_debt(e: &Env) -> Map<Address, i128> {
+    let backstop_address = storage::get_backstop(e);
+    let auction_type = AuctionType::BadDebtAuction as u32;
+    if !storage::has_auction(e, &auction_type, &backstop_address) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let auction_data = storage::get_auction(e, &auction_type, &backstop_address);
+    let last_activity = auction_data.block.max(storage::get_bad_debt_last_fill(e));
+    if e.ledger().sequence() < last_activity + storage::get_write_off_delay(e) {
+        panic_with_error!(e, PoolError::WriteOffNotUnlocked);
+    }
+
+    let mut backstop_state = User::load(e, &backstop_address);
+    if backstop_state.positions.liabilities.is_empty() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    storage::del_auction(e, &auction_type, &backstop_address);
+
+    let mut pool = Pool::load(e);
+    let reserve_list = storage::get_res_list(e);
+    let mut written_off: Map<Address, i128> = map![e];
+    for (reserve_index, liability_balance) in backstop_state.positions.liabilities.iter() {
+        let asset = reserve_list.get_unchecked(reserve_index);
+        let mut reserve = pool.load_reserve(e, &asset, true);
+        backstop_state.default_liabilities(e, &mut reserve, liability_balance);
+        pool.cache_reserve(reserve);
+
+        PoolEvents::defaulted_debt(e, asset.clone(), liability_balance);
+        written_off.set(asset, liability_balance);
+    }
+
+    pool.store_cached_reserves(e);
+    backstop_state.store(e);
+
+    // the backstop was unable to cover the bad debt, so an active pool is moved to on-ice
+    let status = PoolStatus::from_u32(e, pool.config.status);
+    if status == PoolStatus::AdminActive || status == PoolStatus::Active {
+        pool.config.status = PoolStatus::OnIce as u32;
+        storage::set_pool_config(e, &pool.config);
+        PoolEvents::set_status(e, pool.config.status);
+    }
+    written_off
+}
+
+/// Set the number of ledgers a bad debt auction can go unfilled before the backstop's
+/// bad debt can be written off
+///
+/// ### Panics
+/// If the delay is less than the minimum write-off delay
+pub fn execute_set_write_off_delay(e: &Env, ledgers: u32) {
+    if ledgers < MIN_WRITE_OFF_DELAY {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+    storage::set_write_off_delay(e, &ledgers);
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{pool::Positions, storage::PoolConfig, testutils};
+    use crate::{
+        auctions::AuctionData, constants::SCALAR_12, pool::Positions, storage::PoolConfig,
+        testutils,
+    };
 
     use super::*;
     use soroban_sdk::{
@@ -285,4 +355,198 @@ mod tests {
             transfer_bad_debt_to_backstop(&e, &backstop);
         });
     }
+
+    /***** execute_write_off_bad_debt ******/
+
+    #[test]
+    fn test_write_off_bad_debt() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 100 + 17280 * 7,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let backstop = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, reserve_data) = testutils::default_reserve_meta();
+        reserve_config.index = 1;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        let backstop_positions = Positions {
+            liabilities: map![&e, (0, 24_0000000), (1, 25_0000000)],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        let auction_data = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 24_0000000)],
+            lot: map![&e],
+            block: 100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_backstop(&e, &backstop);
+            storage::set_user_positions(&e, &backstop, &backstop_positions);
+            storage::set_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop,
+                &auction_data,
+            );
+
+            e.cost_estimate().budget().reset_unlimited();
+            let written_off = execute_write_off_bad_debt(&e);
+
+            assert_eq!(written_off.len(), 2);
+            assert_eq!(written_off.get_unchecked(underlying_0.clone()), 24_0000000);
+            assert_eq!(written_off.get_unchecked(underlying_1.clone()), 25_0000000);
+
+            let new_backstop_positions = storage::get_user_positions(&e, &backstop);
+            assert_eq!(new_backstop_positions.liabilities.len(), 0);
+            assert!(!storage::has_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop
+            ));
+
+            let reserve_data_0 = storage::get_res_data(&e, &underlying_0);
+            assert_eq!(reserve_data_0.d_supply, 75_0000000 - 24_0000000);
+            assert!(reserve_data_0.b_rate < SCALAR_12);
+            let reserve_data_1 = storage::get_res_data(&e, &underlying_1);
+            assert_eq!(reserve_data_1.d_supply, 75_0000000 - 25_0000000);
+            assert!(reserve_data_1.b_rate < SCALAR_12);
+
+            assert_eq!(storage::get_pool_config(&e).status, 3);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1225)")]
+    fn test_write_off_bad_debt_after_recent_fill_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 100 + 17280 * 7,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let backstop = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        let backstop_positions = Positions {
+            liabilities: map![&e, (0, 24_0000000)],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        let auction_data = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 24_0000000)],
+            lot: map![&e],
+            block: 100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_backstop(&e, &backstop);
+            storage::set_user_positions(&e, &backstop, &backstop_positions);
+            storage::set_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop,
+                &auction_data,
+            );
+            storage::set_bad_debt_last_fill(&e, &101);
+
+            e.cost_estimate().budget().reset_unlimited();
+            execute_write_off_bad_debt(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_write_off_bad_debt_no_auction_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 100 + 17280 * 7,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let backstop = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let backstop_positions = Positions {
+            liabilities: map![&e, (0, 24_0000000)],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            storage::set_backstop(&e, &backstop);
+            storage::set_user_positions(&e, &backstop, &backstop_positions);
+
+            e.cost_estimate().budget().reset_unlimited();
+            execute_write_off_bad_debt(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_set_write_off_delay_under_min_panics() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_write_off_delay(&e, 17279);
+        });
+    }
 }
@@ -1,13 +1,19 @@
 use crate::{
-    constants::{MAX_RESERVES, SCALAR_12, SCALAR_7, SECONDS_PER_WEEK},
+    constants::{
+        MAX_MIN_THRESHOLD, MAX_RESERVES, RESERVE_WIND_DOWN_PERIOD, SCALAR_12, SCALAR_7,
+        SECONDS_PER_WEEK,
+    },
+    emissions,
     errors::PoolError,
     storage::{
-        self, has_queued_reserve_set, PoolConfig, QueuedReserveInit, ReserveConfig, ReserveData,
+        self, has_queued_reserve_set, PoolConfig, QueuedPoolConfig, QueuedReserveInit,
+        QueuedStatusPolicy, ReserveConfig, ReserveData, ReservePause, ReserveWindDown,
+        StatusPolicy,
     },
 };
-use soroban_sdk::{panic_with_error, Address, Env, String};
+use soroban_sdk::{panic_with_error, Address, Env, Map, String, Vec};
 
-use super::pool::Pool;
+use super::{pool::Pool, status::PoolStatus};
 
 /// Initialize the pool
 ///
@@ -28,7 +34,7 @@ pub fn execute_initialize(
         oracle: oracle.clone(),
         min_collateral: *min_collateral,
         bstop_rate: *bstop_rate,
-        status: 6,
+        status: PoolStatus::Setup as u32,
         max_positions: *max_positions,
     };
     require_valid_pool_config(e, &pool_config);
@@ -40,33 +46,104 @@ pub fn execute_initialize(
     storage::set_blnd_token(e, blnd_id);
 }
 
-/// Update the pool
-pub fn execute_update_pool(
+/// Execute queueing an update to the pool
+pub fn execute_queue_update_pool(
     e: &Env,
     backstop_take_rate: u32,
     max_positions: u32,
     min_collateral: i128,
 ) {
+    if storage::has_queued_pool_config(e) {
+        panic_with_error!(&e, PoolError::BadRequest)
+    }
     let mut pool_config = storage::get_pool_config(e);
     pool_config.bstop_rate = backstop_take_rate;
     pool_config.max_positions = max_positions;
     pool_config.min_collateral = min_collateral;
+    require_valid_pool_config(e, &pool_config);
+
+    let mut unlock_time = e.ledger().timestamp();
+    // require a timelock if pool status is not setup
+    if pool_config.status != PoolStatus::Setup as u32 {
+        unlock_time += SECONDS_PER_WEEK;
+    }
+    storage::set_queued_pool_config(
+        e,
+        &QueuedPoolConfig {
+            bstop_rate: backstop_take_rate,
+            max_positions,
+            min_collateral,
+            unlock_time,
+        },
+    );
+}
+
+/// Execute cancelling a queued update to the pool
+pub fn execute_cancel_queued_update_pool(e: &Env) {
+    storage::del_queued_pool_config(e);
+}
+
+/// Execute a queued update to the pool
+pub fn execute_update_pool(e: &Env) -> PoolConfig {
+    let queued_config = storage::get_queued_pool_config(e);
 
+    if queued_config.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+
+    let mut pool_config = storage::get_pool_config(e);
+    pool_config.bstop_rate = queued_config.bstop_rate;
+    pool_config.max_positions = queued_config.max_positions;
+    pool_config.min_collateral = queued_config.min_collateral;
     require_valid_pool_config(e, &pool_config);
+
+    storage::del_queued_pool_config(e);
     storage::set_pool_config(e, &pool_config);
+    pool_config
 }
 
-/// Execute a queueing a reserve initialization for the pool
-pub fn execute_queue_set_reserve(e: &Env, asset: &Address, metadata: &ReserveConfig) {
+/// Execute a queueing a reserve initialization for the pool. Changes to an existing reserve
+/// that only reduce risk are applied immediately.
+///
+/// Returns the index of the reserve if the change was applied immediately
+pub fn execute_queue_set_reserve(
+    e: &Env,
+    asset: &Address,
+    metadata: &ReserveConfig,
+) -> Option<u32> {
+    require_valid_reserve_metadata(e, metadata);
+    if storage::has_res(e, asset) && is_risk_reducing(&storage::get_res_config(e, asset), metadata)
+    {
+        return Some(initialize_reserve(e, asset, metadata));
+    }
+
     if has_queued_reserve_set(e, asset) {
         panic_with_error!(&e, PoolError::BadRequest)
     }
-    require_valid_reserve_metadata(e, metadata);
     let mut unlock_time = e.ledger().timestamp();
     // require a timelock if pool status is not setup
-    if storage::get_pool_config(e).status != 6 {
+    if storage::get_pool_config(e).status != PoolStatus::Setup as u32 {
         unlock_time += SECONDS_PER_WEEK;
     }
+    // index new assets so their queued set can be found, dropping any assets whose
+    // queued set has expired
+    if !storage::has_res(e, asset) {
+        // reward tokens held by the pool can't also be a reserve
+        if emissions::is_reward_token(e, asset) {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+        let mut queued_res_list = Vec::new(e);
+        for queued_asset in storage::get_queued_res_list(e).iter() {
+            if has_queued_reserve_set(e, &queued_asset) {
+                queued_res_list.push_back(queued_asset);
+            }
+        }
+        if queued_res_list.len() >= MAX_RESERVES {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+        queued_res_list.push_back(asset.clone());
+        storage::set_queued_res_list(e, &queued_res_list);
+    }
     storage::set_queued_reserve_set(
         &e,
         &QueuedReserveInit {
@@ -75,11 +152,13 @@ pub fn execute_queue_set_reserve(e: &Env, asset: &Address, metadata: &ReserveCon
         },
         &asset,
     );
+    None
 }
 
 /// Execute cancelling a queueing a reserve initialization for the pool
 pub fn execute_cancel_queued_set_reserve(e: &Env, asset: &Address) {
     storage::del_queued_reserve_set(&e, &asset);
+    remove_from_queued_res_list(e, asset);
 }
 
 /// Execute a queued reserve initialization for the pool
@@ -92,11 +171,124 @@ pub fn execute_set_reserve(e: &Env, asset: &Address) -> u32 {
 
     // remove queued reserve
     storage::del_queued_reserve_set(e, asset);
+    remove_from_queued_res_list(e, asset);
 
     // initialize reserve
     initialize_reserve(e, asset, &queued_init.new_config)
 }
 
+/// Load all queued reserve sets for the pool, for both existing reserves and new assets
+pub fn load_queued_reserves(e: &Env) -> Map<Address, QueuedReserveInit> {
+    let mut queued_reserves = Map::new(e);
+    let res_list = storage::get_res_list(e);
+    let queued_res_list = storage::get_queued_res_list(e);
+    for asset in res_list.iter().chain(queued_res_list.iter()) {
+        if storage::has_queued_reserve_set(e, &asset) {
+            let queued_init = storage::get_queued_reserve_set(e, &asset);
+            queued_reserves.set(asset, queued_init);
+        }
+    }
+    queued_reserves
+}
+
+/// Execute setting the pause switches for a reserve. Takes effect immediately.
+///
+/// ### Panics
+/// If the reserve does not exist
+pub fn execute_set_reserve_pause(e: &Env, asset: &Address, pause: &ReservePause) {
+    if !storage::has_res(e, asset) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    storage::set_res_pause(e, asset, pause);
+}
+
+/// Execute starting the wind-down of a reserve. Takes effect immediately and can't be undone.
+///
+/// ### Panics
+/// If the reserve does not exist or is already being wound down
+pub fn execute_wind_down_reserve(e: &Env, asset: &Address) {
+    if !storage::has_res(e, asset) || storage::get_res_wind_down(e, asset).is_some() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    storage::set_res_wind_down(
+        e,
+        asset,
+        &ReserveWindDown {
+            start_time: e.ledger().timestamp(),
+            retired: false,
+        },
+    );
+}
+
+/// Execute retiring a reserve that has been wound down
+///
+/// ### Panics
+/// If the reserve is not being wound down, is already retired, has not finished its
+/// wind-down period, or has outstanding supply, liabilities or backstop credit
+pub fn execute_retire_reserve(e: &Env, asset: &Address) {
+    let mut wind_down = match storage::get_res_wind_down(e, asset) {
+        Some(wind_down) if !wind_down.retired => wind_down,
+        _ => panic_with_error!(e, PoolError::BadRequest),
+    };
+    let reserve_data = storage::get_res_data(e, asset);
+    if wind_down.start_time + RESERVE_WIND_DOWN_PERIOD > e.ledger().timestamp()
+        || reserve_data.b_supply > 0
+        || reserve_data.d_supply > 0
+        || reserve_data.backstop_credit > 0
+    {
+        panic_with_error!(e, PoolError::ReserveNotWoundDown);
+    }
+    wind_down.retired = true;
+    storage::set_res_wind_down(e, asset, &wind_down);
+}
+
+/// Execute queueing a status policy update for the pool
+pub fn execute_queue_set_status_policy(e: &Env, policy: &StatusPolicy) {
+    if storage::has_queued_status_policy(e) {
+        panic_with_error!(&e, PoolError::BadRequest)
+    }
+    require_valid_status_policy(e, policy);
+    let mut unlock_time = e.ledger().timestamp();
+    // require a timelock if pool status is not setup
+    if storage::get_pool_config(e).status != PoolStatus::Setup as u32 {
+        unlock_time += SECONDS_PER_WEEK;
+    }
+    storage::set_queued_status_policy(
+        &e,
+        &QueuedStatusPolicy {
+            new_policy: policy.clone(),
+            unlock_time,
+        },
+    );
+}
+
+/// Execute cancelling a queued status policy update for the pool
+pub fn execute_cancel_queued_set_status_policy(e: &Env) {
+    storage::del_queued_status_policy(&e);
+}
+
+/// Execute a queued status policy update for the pool
+pub fn execute_set_status_policy(e: &Env) -> StatusPolicy {
+    let queued_policy = storage::get_queued_status_policy(e);
+
+    if queued_policy.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+
+    storage::del_queued_status_policy(e);
+    storage::set_status_policy(e, &queued_policy.new_policy);
+    queued_policy.new_policy
+}
+
+/// Remove an asset from the list of new assets with a queued reserve set, if it exists
+fn remove_from_queued_res_list(e: &Env, asset: &Address) {
+    let mut queued_res_list = storage::get_queued_res_list(e);
+    if let Some(index) = queued_res_list.first_index_of(asset) {
+        queued_res_list.remove(index);
+        storage::set_queued_res_list(e, &queued_res_list);
+    }
+}
+
 /// sets reserve data for the pool
 fn initialize_reserve(e: &Env, asset: &Address, config: &ReserveConfig) -> u32 {
     let index: u32;
@@ -123,6 +315,9 @@ fn initialize_reserve(e: &Env, asset: &Address, config: &ReserveConfig) -> u32 {
         }
         reserve.store(e);
     } else {
+        if emissions::is_reward_token(e, asset) {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
         index = storage::push_res_list(e, asset);
         let init_data = ReserveData {
             b_rate: SCALAR_12,
@@ -156,6 +351,30 @@ fn initialize_reserve(e: &Env, asset: &Address, config: &ReserveConfig) -> u32 {
     index
 }
 
+/// Check if a reserve config change strictly reduces risk. A change reduces risk if it only
+/// lowers the collateral factor, lowers the collateral cap, or disables the reserve.
+///
+/// ### Arguments
+/// * `current` - The current config of the reserve
+/// * `new` - The new config of the reserve
+fn is_risk_reducing(current: &ReserveConfig, new: &ReserveConfig) -> bool {
+    // any risk reducing field can change as long as it does not increase risk
+    let reduces = new.c_factor <= current.c_factor
+        && new.collateral_cap <= current.collateral_cap
+        && (current.enabled || !new.enabled);
+    // all other fields must remain unchanged
+    let unchanged = new.decimals == current.decimals
+        && new.l_factor == current.l_factor
+        && new.util == current.util
+        && new.max_util == current.max_util
+        && new.r_base == current.r_base
+        && new.r_one == current.r_one
+        && new.r_two == current.r_two
+        && new.r_three == current.r_three
+        && new.reactivity == current.reactivity;
+    reduces && unchanged
+}
+
 #[allow(clippy::zero_prefixed_literal)]
 fn require_valid_reserve_metadata(e: &Env, metadata: &ReserveConfig) {
     const SCALAR_7_U32: u32 = SCALAR_7 as u32;
@@ -173,6 +392,25 @@ fn require_valid_reserve_metadata(e: &Env, metadata: &ReserveConfig) {
     }
 }
 
+fn require_valid_status_policy(e: &Env, policy: &StatusPolicy) {
+    const SCALAR_7_U32: u32 = SCALAR_7 as u32;
+    // the on-ice limits must be reached before the frozen limits, and the admin set statuses
+    // must be at least as permissive as the matching permissionless statuses
+    if policy.on_ice_q4w == 0
+        || policy.on_ice_q4w >= policy.frozen_q4w
+        || policy.frozen_q4w > SCALAR_7_U32
+        || policy.admin_on_ice_q4w == 0
+        || policy.admin_on_ice_q4w >= policy.admin_frozen_q4w
+        || policy.admin_frozen_q4w > SCALAR_7_U32
+        || policy.on_ice_q4w > policy.admin_on_ice_q4w
+        || policy.frozen_q4w > policy.admin_frozen_q4w
+        || policy.min_threshold <= 0
+        || policy.min_threshold > MAX_MIN_THRESHOLD
+    {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+}
+
 fn require_valid_pool_config(e: &Env, config: &PoolConfig) {
     // ensure backstop is [0,1)
     if config.bstop_rate >= SCALAR_7 as u32 {
@@ -196,7 +434,10 @@ mod tests {
     use crate::testutils;
 
     use super::*;
-    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec,
+    };
 
     #[test]
     fn test_execute_initialize() {
@@ -300,6 +541,106 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_execute_queue_update_pool() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_update_pool(&e, 0_2000000, 4u32, 2_0000000);
+            let queued_config = storage::get_queued_pool_config(&e);
+            assert_eq!(queued_config.bstop_rate, 0_2000000);
+            assert_eq!(queued_config.max_positions, 4u32);
+            assert_eq!(queued_config.min_collateral, 2_0000000);
+            assert_eq!(
+                queued_config.unlock_time,
+                e.ledger().timestamp() + SECONDS_PER_WEEK
+            );
+
+            // config is not applied until the update is executed
+            let new_pool_config = storage::get_pool_config(&e);
+            assert_eq!(new_pool_config.bstop_rate, 0_1000000);
+            assert_eq!(new_pool_config.max_positions, 2u32);
+            assert_eq!(new_pool_config.min_collateral, 1_0000000);
+        });
+    }
+
+    #[test]
+    fn test_execute_queue_update_pool_status_6() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_update_pool(&e, 0_2000000, 4u32, 2_0000000);
+            let queued_config = storage::get_queued_pool_config(&e);
+            assert_eq!(queued_config.unlock_time, e.ledger().timestamp());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_queue_update_pool_duplicate() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_update_pool(&e, 0_2000000, 4u32, 2_0000000);
+            execute_queue_update_pool(&e, 0_3000000, 4u32, 2_0000000);
+        });
+    }
+
+    #[test]
+    fn test_execute_cancel_queued_update_pool() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_update_pool(&e, 0_2000000, 4u32, 2_0000000);
+            execute_cancel_queued_update_pool(&e);
+            assert!(!storage::has_queued_pool_config(&e));
+        });
+    }
+
     #[test]
     fn test_execute_update_pool() {
         let e = Env::default();
@@ -315,21 +656,62 @@ mod tests {
         };
         e.as_contract(&pool, || {
             storage::set_pool_config(&e, &pool_config);
+            storage::set_queued_pool_config(
+                &e,
+                &QueuedPoolConfig {
+                    bstop_rate: 0_2000000,
+                    max_positions: 4,
+                    min_collateral: 2_0000000,
+                    unlock_time: e.ledger().timestamp(),
+                },
+            );
 
             // happy path
-            execute_update_pool(&e, 0_2000000, 4u32, 2_0000000);
+            let result = execute_update_pool(&e);
+            assert_eq!(result.bstop_rate, 0_2000000);
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.bstop_rate, 0_2000000);
             assert_eq!(new_pool_config.oracle, pool_config.oracle);
             assert_eq!(new_pool_config.status, pool_config.status);
             assert_eq!(new_pool_config.max_positions, 4u32);
             assert_eq!(new_pool_config.min_collateral, 2_0000000);
+            assert!(!storage::has_queued_pool_config(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_execute_update_pool_requires_block_passed() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_queued_pool_config(
+                &e,
+                &QueuedPoolConfig {
+                    bstop_rate: 0_2000000,
+                    max_positions: 4,
+                    min_collateral: 2_0000000,
+                    unlock_time: e.ledger().timestamp() + 1,
+                },
+            );
+
+            execute_update_pool(&e);
         });
     }
 
     #[test]
     #[should_panic(expected = "Error(Contract, #1201)")]
-    fn test_execute_update_pool_validates_b_stop_rate() {
+    fn test_execute_queue_update_pool_validates_b_stop_rate() {
         let e = Env::default();
         e.mock_all_auths();
         let pool = testutils::create_pool(&e);
@@ -344,13 +726,13 @@ mod tests {
         e.as_contract(&pool, || {
             storage::set_pool_config(&e, &pool_config);
 
-            execute_update_pool(&e, 1_0000000, 4u32, 1_0000000);
+            execute_queue_update_pool(&e, 1_0000000, 4u32, 1_0000000);
         });
     }
 
     #[test]
     #[should_panic(expected = "Error(Contract, #1201)")]
-    fn test_execute_update_pool_validates_min_collateral() {
+    fn test_execute_queue_update_pool_validates_min_collateral() {
         let e = Env::default();
         e.mock_all_auths();
         let pool = testutils::create_pool(&e);
@@ -365,13 +747,13 @@ mod tests {
         e.as_contract(&pool, || {
             storage::set_pool_config(&e, &pool_config);
 
-            execute_update_pool(&e, 0_2000000, 4u32, -1);
+            execute_queue_update_pool(&e, 0_2000000, 4u32, -1);
         });
     }
 
     #[test]
     #[should_panic(expected = "Error(Contract, #1201)")]
-    fn test_execute_update_pool_validates_max_positions() {
+    fn test_execute_queue_update_pool_validates_max_positions() {
         let e = Env::default();
         e.mock_all_auths();
         let pool = testutils::create_pool(&e);
@@ -386,7 +768,7 @@ mod tests {
         e.as_contract(&pool, || {
             storage::set_pool_config(&e, &pool_config);
 
-            execute_update_pool(&e, 0_2000000, 1 + 2 * MAX_RESERVES, 2_0000000);
+            execute_queue_update_pool(&e, 0_2000000, 1 + 2 * MAX_RESERVES, 2_0000000);
         });
     }
 
@@ -576,71 +958,657 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_cancel_queued_reserve_initialization() {
+    fn test_queue_set_reserve_risk_reducing_applies_immediately() {
         let e = Env::default();
         e.mock_all_auths();
         let pool = testutils::create_pool(&e);
         let bombadil = Address::generate(&e);
 
-        let (asset_id_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
 
-        let metadata = ReserveConfig {
-            index: 0,
-            decimals: 7,
-            c_factor: 0_7500000,
-            l_factor: 0_7500000,
-            util: 0_5000000,
-            max_util: 0_9500000,
-            r_base: 0_0100000,
-            r_one: 0_0500000,
-            r_two: 0_5000000,
-            r_three: 1_5000000,
-            reactivity: 100,
-            collateral_cap: 1000000000000000000,
-            enabled: true,
+        let mut new_metadata = reserve_config.clone();
+        new_metadata.c_factor -= 0_1000000;
+        new_metadata.collateral_cap = 1000_0000000;
+        new_metadata.enabled = false;
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
         };
         e.as_contract(&pool, || {
-            storage::set_queued_reserve_set(
-                &e,
-                &QueuedReserveInit {
-                    new_config: metadata.clone(),
-                    unlock_time: e.ledger().timestamp(),
-                },
-                &asset_id_0,
-            );
-            execute_cancel_queued_set_reserve(&e, &asset_id_0);
-            let result = storage::has_queued_reserve_set(&e, &asset_id_0);
+            storage::set_pool_config(&e, &pool_config);
 
-            assert!(!result);
+            let result = execute_queue_set_reserve(&e, &underlying, &new_metadata);
+            assert_eq!(result, Some(reserve_config.index));
+            assert!(!storage::has_queued_reserve_set(&e, &underlying));
+
+            let res_config_updated = storage::get_res_config(&e, &underlying);
+            assert_eq!(res_config_updated.c_factor, new_metadata.c_factor);
+            assert_eq!(res_config_updated.collateral_cap, 1000_0000000);
+            assert!(!res_config_updated.enabled);
+            assert_eq!(res_config_updated.l_factor, reserve_config.l_factor);
+            assert_eq!(res_config_updated.index, reserve_config.index);
         });
     }
 
     #[test]
-    fn test_execute_set_reserve_first_reserve() {
+    fn test_queue_set_reserve_risk_increasing_is_queued() {
         let e = Env::default();
         e.mock_all_auths();
         let pool = testutils::create_pool(&e);
         let bombadil = Address::generate(&e);
 
-        let (asset_id_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
 
-        let metadata = ReserveConfig {
-            index: 0,
-            decimals: 7,
-            c_factor: 0_7500000,
-            l_factor: 0_7500000,
-            util: 0_5000000,
-            max_util: 0_9500000,
-            r_base: 0_0100000,
-            r_one: 0_0500000,
-            r_two: 0_5000000,
-            r_three: 1_5000000,
-            reactivity: 100,
-            collateral_cap: 1000000000000000000,
-            enabled: true,
-        };
-        e.as_contract(&pool, || {
-            storage::set_queued_reserve_set(
+        // lowering the c_factor alongside another change is not strictly risk reducing
+        let mut new_metadata = reserve_config.clone();
+        new_metadata.c_factor -= 0_1000000;
+        new_metadata.max_util += 0_0100000;
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            let result = execute_queue_set_reserve(&e, &underlying, &new_metadata);
+            assert_eq!(result, None);
+            let queued_init = storage::get_queued_reserve_set(&e, &underlying);
+            assert_eq!(queued_init.new_config.c_factor, new_metadata.c_factor);
+            assert_eq!(
+                queued_init.unlock_time,
+                e.ledger().timestamp() + SECONDS_PER_WEEK
+            );
+
+            let res_config = storage::get_res_config(&e, &underlying);
+            assert_eq!(res_config.c_factor, reserve_config.c_factor);
+            assert_eq!(res_config.max_util, reserve_config.max_util);
+        });
+    }
+
+    #[test]
+    fn test_is_risk_reducing() {
+        let (reserve_config, _) = testutils::default_reserve_meta();
+
+        // no change
+        assert!(is_risk_reducing(&reserve_config, &reserve_config));
+
+        let mut new_config = reserve_config.clone();
+        new_config.c_factor -= 1;
+        assert!(is_risk_reducing(&reserve_config, &new_config));
+        new_config.c_factor += 2;
+        assert!(!is_risk_reducing(&reserve_config, &new_config));
+
+        let mut new_config = reserve_config.clone();
+        new_config.collateral_cap -= 1;
+        assert!(is_risk_reducing(&reserve_config, &new_config));
+        new_config.collateral_cap += 2;
+        assert!(!is_risk_reducing(&reserve_config, &new_config));
+
+        let mut new_config = reserve_config.clone();
+        new_config.enabled = false;
+        assert!(is_risk_reducing(&reserve_config, &new_config));
+        // enabling a disabled reserve increases risk
+        assert!(!is_risk_reducing(&new_config, &reserve_config));
+
+        let mut new_config = reserve_config.clone();
+        new_config.l_factor -= 1;
+        assert!(!is_risk_reducing(&reserve_config, &new_config));
+
+        let mut new_config = reserve_config.clone();
+        new_config.r_one += 1;
+        assert!(!is_risk_reducing(&reserve_config, &new_config));
+    }
+
+    #[test]
+    fn test_load_queued_reserves() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let mut new_metadata = reserve_config.clone();
+        new_metadata.c_factor += 0_0100000;
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            assert_eq!(load_queued_reserves(&e).len(), 0);
+
+            execute_queue_set_reserve(&e, &underlying_0, &new_metadata);
+            execute_queue_set_reserve(&e, &underlying_1, &reserve_config);
+            execute_queue_set_reserve(&e, &underlying_2, &reserve_config);
+
+            // only new assets are indexed
+            let queued_res_list = storage::get_queued_res_list(&e);
+            assert_eq!(queued_res_list.len(), 2);
+            assert_eq!(queued_res_list.get_unchecked(0), underlying_1);
+            assert_eq!(queued_res_list.get_unchecked(1), underlying_2);
+
+            let queued_reserves = load_queued_reserves(&e);
+            assert_eq!(queued_reserves.len(), 3);
+            assert_eq!(
+                queued_reserves
+                    .get_unchecked(underlying_0.clone())
+                    .new_config
+                    .c_factor,
+                new_metadata.c_factor
+            );
+            assert!(queued_reserves.contains_key(underlying_1.clone()));
+            assert!(queued_reserves.contains_key(underlying_2.clone()));
+
+            // cancelling removes the asset from the index
+            execute_cancel_queued_set_reserve(&e, &underlying_1);
+            let queued_res_list = storage::get_queued_res_list(&e);
+            assert_eq!(queued_res_list.len(), 1);
+            assert_eq!(queued_res_list.get_unchecked(0), underlying_2);
+
+            let queued_reserves = load_queued_reserves(&e);
+            assert_eq!(queued_reserves.len(), 2);
+            assert!(queued_reserves.contains_key(underlying_0.clone()));
+            assert!(queued_reserves.contains_key(underlying_2.clone()));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_queue_set_reserve_panics_if_reward_token() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_reward_tokens(&e, &1, &vec![&e, reward_token.clone()]);
+
+            execute_queue_set_reserve(&e, &reward_token, &reserve_config);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_set_reserve_panics_if_reward_token() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (reward_token, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            // the asset is registered as a reward token after being queued
+            execute_queue_set_reserve(&e, &reward_token, &reserve_config);
+            storage::set_reward_tokens(&e, &0, &vec![&e, reward_token.clone()]);
+
+            execute_set_reserve(&e, &reward_token);
+        });
+    }
+
+    #[test]
+    fn test_execute_queue_set_reserve_prunes_expired_queued_assets() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, _) = testutils::default_reserve_meta();
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_set_reserve(&e, &underlying_0, &reserve_config);
+            // the temporary queued set expires
+            storage::del_queued_reserve_set(&e, &underlying_0);
+
+            execute_queue_set_reserve(&e, &underlying_1, &reserve_config);
+            let queued_res_list = storage::get_queued_res_list(&e);
+            assert_eq!(queued_res_list.len(), 1);
+            assert_eq!(queued_res_list.get_unchecked(0), underlying_1);
+
+            // the expired asset can be queued again
+            execute_queue_set_reserve(&e, &underlying_0, &reserve_config);
+            let queued_res_list = storage::get_queued_res_list(&e);
+            assert_eq!(queued_res_list.len(), 2);
+            assert_eq!(queued_res_list.get_unchecked(1), underlying_0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_queue_set_reserve_queued_res_list_full() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let (reserve_config, _) = testutils::default_reserve_meta();
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            for _ in 0..MAX_RESERVES {
+                execute_queue_set_reserve(&e, &Address::generate(&e), &reserve_config);
+            }
+            assert_eq!(storage::get_queued_res_list(&e).len(), MAX_RESERVES);
+
+            execute_queue_set_reserve(&e, &Address::generate(&e), &reserve_config);
+        });
+    }
+
+    #[test]
+    fn test_execute_set_reserve_removes_from_queued_res_list() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, _) = testutils::default_reserve_meta();
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_set_reserve(&e, &underlying, &reserve_config);
+            assert_eq!(storage::get_queued_res_list(&e).len(), 1);
+
+            execute_set_reserve(&e, &underlying);
+            assert_eq!(storage::get_queued_res_list(&e).len(), 0);
+            assert_eq!(load_queued_reserves(&e).len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_execute_wind_down_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+            let wind_down = storage::get_res_wind_down(&e, &underlying).unwrap();
+            assert_eq!(wind_down.start_time, e.ledger().timestamp());
+            assert!(!wind_down.retired);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_wind_down_reserve_twice_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+            execute_wind_down_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    fn test_execute_retire_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: e.ledger().timestamp() + RESERVE_WIND_DOWN_PERIOD,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&pool, || {
+            execute_retire_reserve(&e, &underlying);
+            let wind_down = storage::get_res_wind_down(&e, &underlying).unwrap();
+            assert!(wind_down.retired);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1229)")]
+    fn test_execute_retire_reserve_before_period_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: e.ledger().timestamp() + RESERVE_WIND_DOWN_PERIOD - 1,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&pool, || {
+            execute_retire_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1229)")]
+    fn test_execute_retire_reserve_with_liabilities_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: e.ledger().timestamp() + RESERVE_WIND_DOWN_PERIOD,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&pool, || {
+            execute_retire_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1229)")]
+    fn test_execute_retire_reserve_with_supply_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: e.ledger().timestamp() + RESERVE_WIND_DOWN_PERIOD,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&pool, || {
+            execute_retire_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1229)")]
+    fn test_execute_retire_reserve_with_backstop_credit_panics() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        reserve_data.d_supply = 0;
+        reserve_data.backstop_credit = 1_0000000;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_wind_down_reserve(&e, &underlying);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: e.ledger().timestamp() + RESERVE_WIND_DOWN_PERIOD,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&pool, || {
+            execute_retire_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_push_res_list_retired_reserve_keeps_slot() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            for _ in 0..MAX_RESERVES {
+                storage::push_res_list(&e, &Address::generate(&e));
+            }
+            storage::set_res_wind_down(
+                &e,
+                &storage::get_res_list(&e).get_unchecked(3),
+                &ReserveWindDown {
+                    start_time: 0,
+                    retired: true,
+                },
+            );
+
+            storage::push_res_list(&e, &Address::generate(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_push_res_list_wound_down_reserve_keeps_slot() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            for _ in 0..MAX_RESERVES {
+                storage::push_res_list(&e, &Address::generate(&e));
+            }
+            storage::set_res_wind_down(
+                &e,
+                &storage::get_res_list(&e).get_unchecked(3),
+                &ReserveWindDown {
+                    start_time: 0,
+                    retired: false,
+                },
+            );
+
+            storage::push_res_list(&e, &Address::generate(&e));
+        });
+    }
+
+    #[test]
+    fn test_execute_cancel_queued_reserve_initialization() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (asset_id_0, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let metadata = ReserveConfig {
+            index: 0,
+            decimals: 7,
+            c_factor: 0_7500000,
+            l_factor: 0_7500000,
+            util: 0_5000000,
+            max_util: 0_9500000,
+            r_base: 0_0100000,
+            r_one: 0_0500000,
+            r_two: 0_5000000,
+            r_three: 1_5000000,
+            reactivity: 100,
+            collateral_cap: 1000000000000000000,
+            enabled: true,
+        };
+        e.as_contract(&pool, || {
+            storage::set_queued_reserve_set(
+                &e,
+                &QueuedReserveInit {
+                    new_config: metadata.clone(),
+                    unlock_time: e.ledger().timestamp(),
+                },
+                &asset_id_0,
+            );
+            execute_cancel_queued_set_reserve(&e, &asset_id_0);
+            let result = storage::has_queued_reserve_set(&e, &asset_id_0);
+
+            assert!(!result);
+        });
+    }
+
+    #[test]
+    fn test_execute_set_reserve_first_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (asset_id_0, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let metadata = ReserveConfig {
+            index: 0,
+            decimals: 7,
+            c_factor: 0_7500000,
+            l_factor: 0_7500000,
+            util: 0_5000000,
+            max_util: 0_9500000,
+            r_base: 0_0100000,
+            r_one: 0_0500000,
+            r_two: 0_5000000,
+            r_three: 1_5000000,
+            reactivity: 100,
+            collateral_cap: 1000000000000000000,
+            enabled: true,
+        };
+        e.as_contract(&pool, || {
+            storage::set_queued_reserve_set(
                 &e,
                 &QueuedReserveInit {
                     new_config: metadata.clone(),
@@ -921,6 +1889,251 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_queue_set_status_policy() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_2000000,
+            frozen_q4w: 0_4000000,
+            admin_on_ice_q4w: 0_3000000,
+            admin_frozen_q4w: 0_5000000,
+            min_threshold: 0_0000100,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+            let queued_policy = storage::get_queued_status_policy(&e);
+            assert_eq!(queued_policy.new_policy.on_ice_q4w, policy.on_ice_q4w);
+            assert_eq!(queued_policy.new_policy.frozen_q4w, policy.frozen_q4w);
+            assert_eq!(
+                queued_policy.new_policy.admin_on_ice_q4w,
+                policy.admin_on_ice_q4w
+            );
+            assert_eq!(
+                queued_policy.new_policy.admin_frozen_q4w,
+                policy.admin_frozen_q4w
+            );
+            assert_eq!(queued_policy.new_policy.min_threshold, policy.min_threshold);
+            assert_eq!(
+                queued_policy.unlock_time,
+                e.ledger().timestamp() + SECONDS_PER_WEEK
+            );
+
+            // policy is not applied until it is set
+            let current_policy = storage::get_status_policy(&e);
+            assert_eq!(current_policy.on_ice_q4w, 0_3000000);
+            assert_eq!(current_policy.frozen_q4w, 0_6000000);
+            assert_eq!(current_policy.admin_on_ice_q4w, 0_5000000);
+            assert_eq!(current_policy.admin_frozen_q4w, 0_7500000);
+            assert_eq!(current_policy.min_threshold, 1_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_validates_policy() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_6000000,
+            frozen_q4w: 0_4000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_7500000,
+            min_threshold: 1_0000000,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_zero_min_threshold() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_7500000,
+            min_threshold: 0,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_min_threshold_over_max() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_7500000,
+            min_threshold: 10_0000001,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_admin_on_ice_below_on_ice() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_2000000,
+            admin_frozen_q4w: 0_7500000,
+            min_threshold: 1_0000000,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_admin_frozen_below_frozen() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_5500000,
+            min_threshold: 1_0000000,
+        };
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 1,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    fn test_execute_set_status_policy() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_2000000,
+            frozen_q4w: 0_4000000,
+            admin_on_ice_q4w: 0_3000000,
+            admin_frozen_q4w: 0_5000000,
+            min_threshold: 0_0000100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_queued_status_policy(
+                &e,
+                &QueuedStatusPolicy {
+                    new_policy: policy.clone(),
+                    unlock_time: e.ledger().timestamp(),
+                },
+            );
+            let result = execute_set_status_policy(&e);
+            assert_eq!(result.on_ice_q4w, policy.on_ice_q4w);
+
+            let new_policy = storage::get_status_policy(&e);
+            assert_eq!(new_policy.on_ice_q4w, policy.on_ice_q4w);
+            assert_eq!(new_policy.frozen_q4w, policy.frozen_q4w);
+            assert_eq!(new_policy.admin_on_ice_q4w, policy.admin_on_ice_q4w);
+            assert_eq!(new_policy.admin_frozen_q4w, policy.admin_frozen_q4w);
+            assert_eq!(new_policy.min_threshold, policy.min_threshold);
+            assert!(!storage::has_queued_status_policy(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_execute_set_status_policy_requires_block_passed() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let pool = testutils::create_pool(&e);
+
+        let policy = StatusPolicy {
+            on_ice_q4w: 0_2000000,
+            frozen_q4w: 0_4000000,
+            admin_on_ice_q4w: 0_3000000,
+            admin_frozen_q4w: 0_5000000,
+            min_threshold: 0_0000100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_queued_status_policy(
+                &e,
+                &QueuedStatusPolicy {
+                    new_policy: policy,
+                    unlock_time: e.ledger().timestamp() + 1,
+                },
+            );
+            execute_set_status_policy(&e);
+        });
+    }
+
     #[test]
     fn test_initialize_reserve_sets_index() {
         let e = Env::default();
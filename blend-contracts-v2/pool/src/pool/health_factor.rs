@@ -28,6 +28,7 @@ impl PositionData {
         let oracle_scalar = 10i128.pow(pool.load_price_decimals(e));
 
         let reserve_list = storage::get_res_list(e);
+        let wind_downs = storage::get_res_wind_downs(e);
         let mut collateral_base = 0;
         let mut liability_base = 0;
         let mut collateral_raw = 0;
@@ -38,7 +39,15 @@ impl PositionData {
             if b_token_balance == 0 && d_token_balance == 0 {
                 continue;
             }
-            let reserve = pool.load_reserve(e, &reserve_list.get_unchecked(i), false);
+            let res_address = reserve_list.get_unchecked(i);
+            // retired reserves have no liabilities and no collateral value
+            if wind_downs
+                .get(res_address.clone())
+                .is_some_and(|wind_down| wind_down.retired)
+            {
+                continue;
+            }
+            let reserve = pool.load_reserve(e, &res_address, false);
             let asset_to_base = pool.load_price(e, &reserve.asset);
 
             if b_token_balance > 0 {
@@ -113,7 +122,10 @@ impl PositionData {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{storage::PoolConfig, testutils};
+    use crate::{
+        storage::{PoolConfig, ReserveWindDown},
+        testutils,
+    };
     use sep_40_oracle::testutils::Asset;
     use soroban_sdk::{
         map,
@@ -206,6 +218,91 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_calculate_from_positions_skips_retired_reserves() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.decimals = 6;
+        reserve_config.index = 1;
+        reserve_data.b_supply = 10_000_000;
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 1000_0000000]);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 0,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 5,
+        };
+
+        let positions = Positions {
+            liabilities: map![&e, (0, 1_5000000)],
+            collateral: map![&e, (0, 100_1234567), (1, 0_250_000)],
+            supply: map![&e],
+        };
+        let positions_without_retired = Positions {
+            liabilities: map![&e, (0, 1_5000000)],
+            collateral: map![&e, (0, 100_1234567)],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_wind_down(
+                &e,
+                &underlying_1,
+                &ReserveWindDown {
+                    start_time: 0,
+                    retired: true,
+                },
+            );
+            let mut pool = Pool::load(&e);
+            let position_data = PositionData::calculate_from_positions(&e, &mut pool, &positions);
+            let expected_data =
+                PositionData::calculate_from_positions(&e, &mut pool, &positions_without_retired);
+            assert_eq!(position_data.collateral_base, expected_data.collateral_base);
+            assert_eq!(position_data.liability_base, expected_data.liability_base);
+            assert_eq!(position_data.collateral_raw, expected_data.collateral_raw);
+            assert_eq!(position_data.liability_raw, expected_data.liability_raw);
+            assert!(position_data.collateral_base > 0);
+        });
+    }
+
     #[test]
     fn test_as_health_factor_rounds_floor() {
         let e = Env::default();
@@ -2,12 +2,17 @@ mod actions;
 pub use actions::{FlashLoan, Request, RequestType};
 
 mod bad_debt;
-pub use bad_debt::transfer_bad_debt_to_backstop;
+pub use bad_debt::{
+    execute_set_write_off_delay, execute_write_off_bad_debt, transfer_bad_debt_to_backstop,
+};
 
 mod config;
 pub use config::{
-    execute_cancel_queued_set_reserve, execute_initialize, execute_queue_set_reserve,
-    execute_set_reserve, execute_update_pool,
+    execute_cancel_queued_set_reserve, execute_cancel_queued_set_status_policy,
+    execute_cancel_queued_update_pool, execute_initialize, execute_queue_set_reserve,
+    execute_queue_set_status_policy, execute_queue_update_pool, execute_retire_reserve,
+    execute_set_reserve, execute_set_reserve_pause, execute_set_status_policy, execute_update_pool,
+    execute_wind_down_reserve, load_queued_reserves,
 };
 
 mod health_factor;
@@ -31,7 +36,7 @@ pub use user::{Positions, User};
 
 mod status;
 pub use status::{
-    calc_pool_backstop_threshold, execute_set_pool_status, execute_update_pool_status,
+    calc_pool_backstop_threshold, execute_set_pool_status, execute_update_pool_status, PoolStatus,
 };
 
 mod gulp;
@@ -8,7 +8,7 @@ use crate::{
     Positions,
 };
 
-use super::reserve::Reserve;
+use super::{reserve::Reserve, status::PoolStatus, RequestType};
 
 pub struct Pool {
     pub config: PoolConfig,
@@ -73,10 +73,8 @@ impl Pool {
     /// ### Arguments
     /// * `action_type` - The type of action being performed
     pub fn require_action_allowed(&self, e: &Env, action_type: u32) {
-        // disable borrowing or auction cancellation for any non-active pool and disable supplying for any frozen pool
-        if (self.config.status > 1 && (action_type == 4 || action_type == 9))
-            || (self.config.status > 3 && (action_type == 2 || action_type == 0))
-        {
+        let status = PoolStatus::from_u32(e, self.config.status);
+        if !status.allows_request(&RequestType::from_u32(e, action_type)) {
             panic_with_error!(e, PoolError::InvalidPoolStatus);
         }
     }
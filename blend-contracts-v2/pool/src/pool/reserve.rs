@@ -1,12 +1,12 @@
-use cast::i128;
+use cast::{i128, u32};
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, Address, Env};
 
 use crate::{
-    constants::{SCALAR_12, SCALAR_7},
+    constants::{RESERVE_WIND_DOWN_PERIOD, SCALAR_12, SCALAR_7},
     errors::PoolError,
     pool::actions::RequestType,
-    storage::{self, PoolConfig, ReserveConfig, ReserveData},
+    storage::{self, PoolConfig, ReserveConfig, ReserveData, ReserveWindDown},
 };
 
 use super::interest::calc_accrual;
@@ -41,6 +41,9 @@ impl Reserve {
             config: reserve_config,
             data: reserve_data,
         };
+        if let Some(wind_down) = storage::get_res_wind_down(e, asset) {
+            reserve.apply_wind_down(e, &wind_down);
+        }
 
         // short circuit if the reserve has already been updated this ledger
         if e.ledger().timestamp() == reserve.data.last_time {
@@ -78,6 +81,27 @@ impl Reserve {
         reserve
     }
 
+    /// Apply a wind-down to the reserve's config. The reserve is disabled, and the collateral
+    /// factor ramps linearly to 0 over the wind-down period.
+    ///
+    /// ### Arguments
+    /// * wind_down - The wind-down of the reserve
+    fn apply_wind_down(&mut self, e: &Env, wind_down: &ReserveWindDown) {
+        self.config.enabled = false;
+        let elapsed = e.ledger().timestamp().saturating_sub(wind_down.start_time);
+        if elapsed >= RESERVE_WIND_DOWN_PERIOD {
+            self.config.c_factor = 0;
+        } else {
+            let remaining = RESERVE_WIND_DOWN_PERIOD - elapsed;
+            self.config.c_factor = u32(i128(self.config.c_factor).fixed_mul_floor(
+                e,
+                &i128(remaining),
+                &i128(RESERVE_WIND_DOWN_PERIOD),
+            ))
+            .unwrap_optimized();
+        }
+    }
+
     /// Store the updated reserve to the ledger.
     pub fn store(&self, e: &Env) {
         storage::set_res_data(e, &self.asset, &self.data);
@@ -273,6 +297,72 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_load_reserve_wind_down() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 3_000_000,
+            protocol_version: 22,
+            sequence_number: 123456,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let oracle = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 5,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_wind_down(
+                &e,
+                &underlying,
+                &ReserveWindDown {
+                    start_time: e.ledger().timestamp() - RESERVE_WIND_DOWN_PERIOD / 4,
+                    retired: false,
+                },
+            );
+
+            // a quarter of the way through the wind-down
+            let reserve = Reserve::load(&e, &pool_config, &underlying);
+            assert_eq!(reserve.config.c_factor, 0_5625000);
+            assert!(!reserve.config.enabled);
+
+            // the stored config is unchanged
+            let stored_config = storage::get_res_config(&e, &underlying);
+            assert_eq!(stored_config.c_factor, reserve_config.c_factor);
+            assert!(stored_config.enabled);
+
+            storage::set_res_wind_down(
+                &e,
+                &underlying,
+                &ReserveWindDown {
+                    start_time: e.ledger().timestamp() - RESERVE_WIND_DOWN_PERIOD,
+                    retired: false,
+                },
+            );
+            let reserve = Reserve::load(&e, &pool_config, &underlying);
+            assert_eq!(reserve.config.c_factor, 0);
+            assert!(!reserve.config.enabled);
+        });
+    }
+
     #[test]
     fn test_load_reserve_accrues_b_rate() {
         let e = Env::default();
@@ -3,13 +3,94 @@ use crate::{
     dependencies::{BackstopClient, PoolBackstopData},
     storage, PoolError,
 };
-use soroban_sdk::{panic_with_error, Env};
+use cast::i128;
+use soroban_sdk::{contracttype, panic_with_error, Env};
+
+use super::RequestType;
+
+/// The status of the pool. Admin set statuses are even numbers, backstop set statuses are odd numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum PoolStatus {
+    AdminActive = 0,
+    Active = 1,
+    AdminOnIce = 2,
+    OnIce = 3,
+    AdminFrozen = 4,
+    Frozen = 5,
+    Setup = 6,
+}
+
+impl PoolStatus {
+    /// Convert a u32 to a PoolStatus
+    ///
+    /// ### Panics
+    /// If the value is not a valid PoolStatus
+    pub fn from_u32(e: &Env, value: u32) -> Self {
+        match value {
+            0 => PoolStatus::AdminActive,
+            1 => PoolStatus::Active,
+            2 => PoolStatus::AdminOnIce,
+            3 => PoolStatus::OnIce,
+            4 => PoolStatus::AdminFrozen,
+            5 => PoolStatus::Frozen,
+            6 => PoolStatus::Setup,
+            _ => panic_with_error!(e, PoolError::BadRequest),
+        }
+    }
+
+    /// Check if a request type is allowed while the pool is in this status
+    ///
+    /// ### Arguments
+    /// * `request_type` - The type of request being made against the pool
+    pub fn allows_request(&self, request_type: &RequestType) -> bool {
+        match self {
+            // all pool operations are permitted
+            PoolStatus::AdminActive | PoolStatus::Active => true,
+            // borrowing and cancelling liquidations are not permitted
+            PoolStatus::AdminOnIce | PoolStatus::OnIce => !matches!(
+                request_type,
+                RequestType::Borrow | RequestType::DeleteLiquidationAuction
+            ),
+            // borrowing, cancelling liquidations, and supplying are not permitted
+            PoolStatus::AdminFrozen | PoolStatus::Frozen | PoolStatus::Setup => !matches!(
+                request_type,
+                RequestType::Borrow
+                    | RequestType::DeleteLiquidationAuction
+                    | RequestType::Supply
+                    | RequestType::SupplyCollateral
+            ),
+        }
+    }
+
+    /// Check if the pool can move from this status to the `new_status`
+    ///
+    /// ### Arguments
+    /// * `new_status` - The status the pool is moving to
+    pub fn can_transition_to(&self, new_status: &PoolStatus) -> bool {
+        match (self, new_status) {
+            // a pool can never be returned to setup
+            (_, PoolStatus::Setup) => false,
+            // a pool must leave setup through the admin before the backstop can activate or freeze it
+            (PoolStatus::Setup, PoolStatus::Active | PoolStatus::Frozen) => false,
+            // the backstop cannot override an admin freeze
+            (PoolStatus::AdminFrozen, PoolStatus::Active | PoolStatus::Frozen) => false,
+            // the backstop can only freeze an admin on-ice pool
+            (PoolStatus::AdminOnIce, PoolStatus::Active) => false,
+            // the backstop can only put an admin active pool on-ice
+            (PoolStatus::AdminActive, PoolStatus::Active | PoolStatus::Frozen) => false,
+            _ => true,
+        }
+    }
+}
 
 /// Update the pool status based on the backstop module
 #[allow(clippy::zero_prefixed_literal)]
 #[allow(clippy::inconsistent_digit_grouping)]
 pub fn execute_update_pool_status(e: &Env) -> u32 {
     let mut pool_config = storage::get_pool_config(e);
+    let policy = storage::get_status_policy(e);
 
     // check the pool has met minimum backstop deposits
     let backstop_id = storage::get_backstop(e);
@@ -18,49 +99,52 @@ pub fn execute_update_pool_status(e: &Env) -> u32 {
     let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
     let threshold = calc_pool_backstop_threshold(&pool_backstop_data);
     let mut met_threshold = true;
-    if threshold < SCALAR_7 {
+    if threshold < policy.min_threshold {
         met_threshold = false;
     }
 
-    match pool_config.status {
-        // Setup
-        6 => {
+    let current_status = PoolStatus::from_u32(e, pool_config.status);
+    let new_status = match current_status {
+        PoolStatus::Setup => {
             // Setup supersedes all other statuses
             panic_with_error!(e, PoolError::StatusNotAllowed);
         }
-        // Admin frozen
-        4 => {
+        PoolStatus::AdminFrozen => {
             // Admin frozen supersedes all other statuses
             panic_with_error!(e, PoolError::StatusNotAllowed);
         }
-        // Admin on-ice
-        2 => {
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
-                // Q4W over 75% freezes the pool
-                pool_config.status = 5;
+        PoolStatus::AdminOnIce => {
+            if pool_backstop_data.q4w_pct >= i128(policy.admin_frozen_q4w) {
+                // Q4W over the admin frozen limit (default 75%) freezes the pool
+                PoolStatus::Frozen
+            } else {
+                current_status
             }
         }
-        // Admin active
-        0 => {
-            if !met_threshold || pool_backstop_data.q4w_pct >= 0_5000000 {
-                // Q4w over 50% or being under threshold puts the pool on-ice
-                pool_config.status = 3;
+        PoolStatus::AdminActive => {
+            if !met_threshold || pool_backstop_data.q4w_pct >= i128(policy.admin_on_ice_q4w) {
+                // Q4w over the admin on-ice limit (default 50%) or being under threshold puts the pool on-ice
+                PoolStatus::OnIce
+            } else {
+                current_status
             }
         }
         // Admin status isn't set
-        _ => {
-            if pool_backstop_data.q4w_pct >= 0_6000000 {
-                // Q4w over 60% sets pool to Frozen
-                pool_config.status = 5;
-            } else if pool_backstop_data.q4w_pct >= 0_3000000 || !met_threshold {
-                // Q4w over 30% sets pool to On-Ice
-                pool_config.status = 3;
+        PoolStatus::Active | PoolStatus::OnIce | PoolStatus::Frozen => {
+            if pool_backstop_data.q4w_pct >= i128(policy.frozen_q4w) {
+                // Q4w over the frozen limit (default 60%) sets pool to Frozen
+                PoolStatus::Frozen
+            } else if pool_backstop_data.q4w_pct >= i128(policy.on_ice_q4w) || !met_threshold {
+                // Q4w over the on-ice limit (default 30%) sets pool to On-Ice
+                PoolStatus::OnIce
             } else {
                 // Backstop is healthy and the pool is set to Active
-                pool_config.status = 1;
+                PoolStatus::Active
             }
         }
-    }
+    };
+    require_valid_transition(e, &current_status, &new_status);
+    pool_config.status = new_status as u32;
     storage::set_pool_config(e, &pool_config);
     pool_config.status
 }
@@ -70,6 +154,14 @@ pub fn execute_update_pool_status(e: &Env) -> u32 {
 #[allow(clippy::inconsistent_digit_grouping)]
 pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
     let mut pool_config = storage::get_pool_config(e);
+    let policy = storage::get_status_policy(e);
+    let current_status = PoolStatus::from_u32(e, pool_config.status);
+    let new_status = PoolStatus::from_u32(e, pool_status);
+    if new_status == PoolStatus::Active || new_status == PoolStatus::Frozen {
+        // Active and Frozen can only be set by the backstop
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    require_valid_transition(e, &current_status, &new_status);
 
     // check the pool has met minimum backstop deposits
     let backstop_id = storage::get_backstop(e);
@@ -77,45 +169,49 @@ pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
 
     let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
 
-    match pool_status {
-        0 => {
-            // Threshold must be met and q4w must be under 50% for the admin to set Active
-            if calc_pool_backstop_threshold(&pool_backstop_data) < SCALAR_7
-                || pool_backstop_data.q4w_pct >= 0_5000000
+    match new_status {
+        PoolStatus::AdminActive => {
+            // Threshold must be met and q4w must be under the admin on-ice limit (default 50%)
+            // for the admin to set Active
+            if calc_pool_backstop_threshold(&pool_backstop_data) < policy.min_threshold
+                || pool_backstop_data.q4w_pct >= i128(policy.admin_on_ice_q4w)
             {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
-            // Admin Active
-            pool_config.status = 0;
         }
-        2 => {
-            // Q4w must be under 75% for admin to set On-Ice
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
+        PoolStatus::AdminOnIce => {
+            // Q4w must be under the admin frozen limit (default 75%) for admin to set On-Ice
+            if pool_backstop_data.q4w_pct >= i128(policy.admin_frozen_q4w) {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
-            // Admin On-Ice
-            pool_config.status = 2;
         }
-        3 => {
-            // Q4w must be under 75% for admin to set permissionless On-Ice
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
+        PoolStatus::OnIce => {
+            // Q4w must be under the admin frozen limit (default 75%) for admin to set permissionless On-Ice
+            if pool_backstop_data.q4w_pct >= i128(policy.admin_frozen_q4w) {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
-            // On-Ice
-            pool_config.status = 3;
         }
-        4 => {
+        PoolStatus::AdminFrozen => {
             // Admin can always freeze the pool
-            // Admin Frozen
-            pool_config.status = 4;
         }
         _ => {
             panic_with_error!(e, PoolError::BadRequest);
         }
     }
+    pool_config.status = new_status as u32;
     storage::set_pool_config(e, &pool_config);
 }
 
+/// Require that the pool is allowed to move from `current_status` to `new_status`
+///
+/// ### Panics
+/// If the transition is not allowed
+pub fn require_valid_transition(e: &Env, current_status: &PoolStatus, new_status: &PoolStatus) {
+    if !current_status.can_transition_to(new_status) {
+        panic_with_error!(e, PoolError::InvalidStatusTransition);
+    }
+}
+
 /// Calculate the threshold for the pool's backstop balance
 ///
 /// Returns the threshold as a percentage^5 in SCALAR_7 points such that SCALAR_7 = 100%
@@ -150,7 +246,7 @@ pub fn calc_pool_backstop_threshold(pool_backstop_data: &PoolBackstopData) -> i1
 #[cfg(test)]
 mod tests {
     use crate::{
-        storage::PoolConfig,
+        storage::{PoolConfig, StatusPolicy},
         testutils::{create_backstop, create_comet_lp_pool, create_pool, create_token_contract},
     };
 
@@ -514,6 +610,100 @@ mod tests {
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1226)")]
+    fn test_set_pool_status_setup_panics() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        let pool_id = create_pool(&e);
+        let oracle_id = Address::generate(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd, blnd_client) = create_token_contract(&e, &bombadil);
+        let (usdc, usdc_client) = create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) = create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (_, backstop_client) = create_backstop(&e, &pool_id, &lp_token, &usdc, &blnd);
+
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_id, &50_000_0000000);
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 0,
+            bstop_rate: 0,
+            status: 2,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_id, || {
+            storage::set_admin(&e, &bombadil);
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_set_pool_status(&e, 6);
+        });
+    }
+
+    #[test]
+    fn test_pool_status_transitions() {
+        let statuses = [
+            PoolStatus::AdminActive,
+            PoolStatus::Active,
+            PoolStatus::AdminOnIce,
+            PoolStatus::OnIce,
+            PoolStatus::AdminFrozen,
+            PoolStatus::Frozen,
+            PoolStatus::Setup,
+        ];
+        for status in statuses.iter() {
+            // nothing can return to setup
+            assert!(!status.can_transition_to(&PoolStatus::Setup));
+            // the admin statuses and permissionless on-ice can be reached from any status
+            assert!(status.can_transition_to(&PoolStatus::AdminActive));
+            assert!(status.can_transition_to(&PoolStatus::AdminOnIce));
+            assert!(status.can_transition_to(&PoolStatus::OnIce));
+            assert!(status.can_transition_to(&PoolStatus::AdminFrozen));
+        }
+
+        assert!(!PoolStatus::Setup.can_transition_to(&PoolStatus::Active));
+        assert!(!PoolStatus::Setup.can_transition_to(&PoolStatus::Frozen));
+        assert!(!PoolStatus::AdminFrozen.can_transition_to(&PoolStatus::Active));
+        assert!(!PoolStatus::AdminFrozen.can_transition_to(&PoolStatus::Frozen));
+        assert!(!PoolStatus::AdminOnIce.can_transition_to(&PoolStatus::Active));
+        assert!(PoolStatus::AdminOnIce.can_transition_to(&PoolStatus::Frozen));
+        assert!(!PoolStatus::AdminActive.can_transition_to(&PoolStatus::Active));
+        assert!(!PoolStatus::AdminActive.can_transition_to(&PoolStatus::Frozen));
+        assert!(PoolStatus::Frozen.can_transition_to(&PoolStatus::Active));
+        assert!(PoolStatus::OnIce.can_transition_to(&PoolStatus::Frozen));
+    }
+
+    #[test]
+    fn test_pool_status_allows_request() {
+        assert!(PoolStatus::Active.allows_request(&RequestType::Borrow));
+        assert!(PoolStatus::AdminActive.allows_request(&RequestType::DeleteLiquidationAuction));
+
+        assert!(!PoolStatus::OnIce.allows_request(&RequestType::Borrow));
+        assert!(!PoolStatus::AdminOnIce.allows_request(&RequestType::DeleteLiquidationAuction));
+        assert!(PoolStatus::OnIce.allows_request(&RequestType::SupplyCollateral));
+
+        assert!(!PoolStatus::Frozen.allows_request(&RequestType::Supply));
+        assert!(!PoolStatus::AdminFrozen.allows_request(&RequestType::SupplyCollateral));
+        assert!(!PoolStatus::Setup.allows_request(&RequestType::Borrow));
+        assert!(PoolStatus::Frozen.allows_request(&RequestType::Repay));
+        assert!(PoolStatus::Frozen.allows_request(&RequestType::WithdrawCollateral));
+        assert!(PoolStatus::Frozen.allows_request(&RequestType::FillUserLiquidationAuction));
+    }
+
     #[test]
     fn test_update_pool_status_active() {
         let e = Env::default();
@@ -703,6 +893,64 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_update_pool_status_uses_status_policy() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        let pool_id = create_pool(&e);
+        let oracle_id = Address::generate(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd, blnd_client) = create_token_contract(&e, &bombadil);
+        let (usdc, usdc_client) = create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) = create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (_, backstop_client) = create_backstop(&e, &pool_id, &lp_token, &usdc, &blnd);
+
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_id, &50_000_0000000);
+        backstop_client.queue_withdrawal(&samwise, &pool_id, &15_000_0000000);
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 0,
+            bstop_rate: 0,
+            status: 1,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_id, || {
+            storage::set_admin(&e, &bombadil);
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_status_policy(
+                &e,
+                &StatusPolicy {
+                    on_ice_q4w: 0_4000000,
+                    frozen_q4w: 0_6000000,
+                    admin_on_ice_q4w: 0_5000000,
+                    admin_frozen_q4w: 0_7500000,
+                    min_threshold: 1_0000000,
+                },
+            );
+
+            let status = execute_update_pool_status(&e);
+
+            let new_pool_config = storage::get_pool_config(&e);
+            assert_eq!(new_pool_config.status, status);
+            assert_eq!(status, 1);
+        });
+    }
+
     #[test]
     fn test_update_pool_status_on_ice_30_q4w_admin_active() {
         let e = Env::default();
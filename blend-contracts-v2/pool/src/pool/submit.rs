@@ -80,6 +80,16 @@ pub fn execute_submit_with_flash_loan(
 
     let prev_positions_count = from_state.positions.effective_count();
 
+    // flash loans are a borrow, so they are blocked if either is paused for the reserve
+    let pause = storage::get_res_pause(e, &flash_loan.asset);
+    if pause.flash_loan || pause.borrow {
+        panic_with_error!(e, PoolError::ReservePaused);
+    }
+    // reserves being wound down can't be borrowed, including via flash loan
+    if storage::get_res_wind_down(e, &flash_loan.asset).is_some() {
+        panic_with_error!(e, PoolError::ReserveDisabled);
+    }
+
     // note: we add the flash loan liabilities before processing the other
     // requests.
     {
@@ -222,7 +232,7 @@ fn handle_transfers(e: &Env, actions: &Actions, spender: &Address, to: &Address)
 #[cfg(test)]
 mod tests {
     use crate::{
-        storage::{self, PoolConfig},
+        storage::{self, PoolConfig, ReserveWindDown},
         testutils, AuctionData, RequestType,
     };
 
@@ -1762,6 +1772,95 @@ mod tests {
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1223)")]
+    fn test_submit_with_flash_loan_wound_down_reserve() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (flash_loan_receiver, _) = testutils::create_flashloan_receiver(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.max_util = 9500000;
+        reserve_data.b_supply = 100_0000000;
+        reserve_data.d_supply = 50_0000000;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, underlying_1_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_wind_down(
+                &e,
+                &underlying_0,
+                &ReserveWindDown {
+                    start_time: 0,
+                    retired: false,
+                },
+            );
+
+            underlying_1_client.mint(&samwise, &25_0000000);
+            underlying_1_client.approve(&samwise, &pool, &100_0000000, &10000);
+
+            // asset_0 is being wound down, so it can't be flash loaned
+            let flash_loan: FlashLoan = FlashLoan {
+                contract: flash_loan_receiver,
+                asset: underlying_0,
+                amount: 25_0000000,
+            };
+
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::SupplyCollateral as u32,
+                    address: underlying_1,
+                    amount: 25_0000000,
+                },
+            ];
+            execute_submit_with_flash_loan(&e, &samwise, flash_loan, requests);
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #1207)")]
     fn test_submit_with_flash_loan_checks_max_util() {
@@ -3,7 +3,15 @@ use soroban_sdk::{
     String, Symbol, TryFromVal, Val, Vec,
 };
 
-use crate::{auctions::AuctionData, constants::MAX_RESERVES, pool::Positions, PoolError};
+use crate::{
+    auctions::AuctionData,
+    constants::{
+        DEFAULT_ADMIN_FROZEN_Q4W, DEFAULT_ADMIN_ON_ICE_Q4W, DEFAULT_FROZEN_Q4W,
+        DEFAULT_MIN_THRESHOLD, DEFAULT_ON_ICE_Q4W, DEFAULT_WRITE_OFF_DELAY, MAX_RESERVES,
+    },
+    pool::Positions,
+    PoolError,
+};
 
 /********** Ledger Thresholds **********/
 
@@ -58,6 +66,73 @@ pub struct ReserveConfig {
     pub enabled: bool,        // the enabled flag of the reserve
 }
 
+/// A proposed admin that has not yet accepted the role
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub admin: Address,          // the proposed admin
+    pub expiration: Option<u64>, // the timestamp the proposal expires at, if any
+}
+
+/// The privileged roles of a pool. Roles are granted and revoked by the admin, who
+/// acts as any role that has not been granted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum PoolRole {
+    RiskManager = 0,      // manages the pool and reserve risk parameters
+    EmissionsManager = 1, // manages the pool's emission configuration
+    Guardian = 2,         // pauses reserves and freezes the pool
+}
+
+/// The pause switches for a reserve. A paused action can not be taken against the reserve,
+/// but positions can still be reduced.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct ReservePause {
+    pub supply: bool,     // pause supplying the reserve
+    pub borrow: bool,     // pause borrowing the reserve
+    pub collateral: bool, // pause supplying the reserve as collateral
+    pub flash_loan: bool, // pause flash loans of the reserve
+}
+
+/// The wind-down of a reserve. A reserve being wound down can't be supplied or borrowed, and
+/// its collateral factor ramps to 0 over the wind-down period.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReserveWindDown {
+    pub start_time: u64, // the timestamp the wind-down started at
+    pub retired: bool,   // if the reserve is retired and ignored by the pool
+}
+
+/// The pool's status policy, defining the backstop conditions that trigger status changes
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct StatusPolicy {
+    pub on_ice_q4w: u32, // the backstop Q4W percentage that puts the pool on-ice, expressed in 7 decimals
+    pub frozen_q4w: u32, // the backstop Q4W percentage that freezes the pool, expressed in 7 decimals
+    pub admin_on_ice_q4w: u32, // the backstop Q4W percentage that puts an admin active pool on-ice, expressed in 7 decimals
+    pub admin_frozen_q4w: u32, // the backstop Q4W percentage that freezes an admin on-ice pool, expressed in 7 decimals
+    pub min_threshold: i128, // the minimum backstop threshold for the pool to be active, expressed in 7 decimals
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedStatusPolicy {
+    pub new_policy: StatusPolicy,
+    pub unlock_time: u64,
+}
+
+/// A queued update to the pool's config
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedPoolConfig {
+    pub bstop_rate: u32,      // the new backstop take rate, expressed in 7 decimals
+    pub max_positions: u32, // the new maximum number of effective positions a single user can hold
+    pub min_collateral: i128, // the new minimum amount of collateral required to open a liability position
+    pub unlock_time: u64,     // the timestamp the update can be applied at
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct QueuedReserveInit {
@@ -88,6 +163,17 @@ pub struct ReserveEmissionData {
     pub last_time: u64,
 }
 
+/// The emission schedule for a reserve b or d token
+///
+/// A scheduled reserve token only receives a share of gulped emissions while the
+/// ledger timestamp is within `[start_time, end_time)`, and never emits past `end_time`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReserveEmissionSchedule {
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
 /// The user emission data for the reserve b or d token
 #[derive(Clone)]
 #[contracttype]
@@ -99,12 +185,21 @@ pub struct UserEmissionData {
 /********** Storage Key Types **********/
 
 const ADMIN_KEY: &str = "Admin";
+const PENDING_ADMIN_KEY: &str = "PendingAdmin";
 const NAME_KEY: &str = "Name";
 const BACKSTOP_KEY: &str = "Backstop";
 const BLND_TOKEN_KEY: &str = "BLNDTkn";
 const POOL_CONFIG_KEY: &str = "Config";
+const QUEUED_POOL_CONFIG_KEY: &str = "QConfig";
 const RES_LIST_KEY: &str = "ResList";
+const QUEUED_RES_LIST_KEY: &str = "QResList";
 const POOL_EMIS_KEY: &str = "PoolEmis";
+const POOL_EMIS_SCHEDULE_KEY: &str = "PoolEmisSched";
+const WRITE_OFF_DELAY_KEY: &str = "WODelay";
+const BAD_DEBT_FILL_KEY: &str = "BDFill";
+const STATUS_POLICY_KEY: &str = "StatusPolicy";
+const QUEUED_STATUS_POLICY_KEY: &str = "QStatusPolicy";
+const RES_WIND_DOWN_KEY: &str = "ResWindDown";
 
 #[derive(Clone)]
 #[contracttype]
@@ -113,6 +208,21 @@ pub struct UserReserveKey {
     reserve_id: u32,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct RewardKey {
+    reserve_id: u32,
+    reward_token: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct UserRewardKey {
+    user: Address,
+    reserve_id: u32,
+    reward_token: Address,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct AuctionKey {
@@ -137,6 +247,16 @@ pub enum PoolDataKey {
     UserEmis(UserReserveKey),
     // The auction's data
     Auction(AuctionKey),
+    // A map of underlying asset's contract address to reserve pause switches
+    ResPause(Address),
+    // The address granted a pool role
+    Role(PoolRole),
+    // The reward tokens registered for a reserve token
+    RewardTkns(u32),
+    // The reward data for a reserve token and reward token
+    RewardData(RewardKey),
+    // The reward information for a reserve token and reward token for a user
+    UserReward(UserRewardKey),
 }
 
 /********** Storage **********/
@@ -166,6 +286,20 @@ fn get_persistent_default<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>, F: FnOn
     }
 }
 
+/// Extend the TTL of an entry in persistent storage if it exists
+fn extend_persistent_if_exists<K: IntoVal<Env, Val>>(
+    e: &Env,
+    key: &K,
+    bump_threshold: u32,
+    bump_amount: u32,
+) {
+    if e.storage().persistent().has(key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, bump_threshold, bump_amount);
+    }
+}
+
 /********** User **********/
 
 /// Fetch the user's positions or return an empty Positions struct
@@ -198,6 +332,43 @@ pub fn set_user_positions(e: &Env, user: &Address, positions: &Positions) {
         .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
 }
 
+/// Extend the TTL of all persistent entries owned by a user without modifying them
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn bump_user(e: &Env, user: &Address) {
+    extend_persistent_if_exists(
+        e,
+        &PoolDataKey::Positions(user.clone()),
+        LEDGER_THRESHOLD_USER,
+        LEDGER_BUMP_USER,
+    );
+    let res_count = get_res_list(e).len();
+    for reserve_id in 0..res_count * 2 {
+        extend_persistent_if_exists(
+            e,
+            &PoolDataKey::UserEmis(UserReserveKey {
+                user: user.clone(),
+                reserve_id,
+            }),
+            LEDGER_THRESHOLD_USER,
+            LEDGER_BUMP_USER,
+        );
+        for reward_token in get_reward_tokens(e, &reserve_id).iter() {
+            extend_persistent_if_exists(
+                e,
+                &PoolDataKey::UserReward(UserRewardKey {
+                    user: user.clone(),
+                    reserve_id,
+                    reward_token,
+                }),
+                LEDGER_THRESHOLD_USER,
+                LEDGER_BUMP_USER,
+            );
+        }
+    }
+}
+
 /********** Admin **********/
 
 // Fetch the current admin Address
@@ -221,6 +392,68 @@ pub fn set_admin(e: &Env, new_admin: &Address) {
         .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), new_admin);
 }
 
+/// Fetch the pending admin, if one has been proposed
+pub fn get_pending_admin(e: &Env) -> Option<PendingAdmin> {
+    e.storage()
+        .instance()
+        .get::<Symbol, PendingAdmin>(&Symbol::new(e, PENDING_ADMIN_KEY))
+}
+
+/// Set the pending admin
+///
+/// ### Arguments
+/// * `pending_admin` - The proposed admin and the proposal's expiration
+pub fn set_pending_admin(e: &Env, pending_admin: &PendingAdmin) {
+    e.storage()
+        .instance()
+        .set::<Symbol, PendingAdmin>(&Symbol::new(e, PENDING_ADMIN_KEY), pending_admin);
+}
+
+/// Delete the pending admin
+pub fn del_pending_admin(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, PENDING_ADMIN_KEY));
+}
+
+/// Fetch the Address granted a role, or the admin if the role has not been granted
+///
+/// ### Arguments
+/// * `role` - The role to fetch
+pub fn get_role(e: &Env, role: &PoolRole) -> Address {
+    e.storage()
+        .instance()
+        .get::<PoolDataKey, Address>(&PoolDataKey::Role(*role))
+        .unwrap_or_else(|| get_admin(e))
+}
+
+/// Check if a role has been granted
+///
+/// ### Arguments
+/// * `role` - The role to check
+pub fn has_role(e: &Env, role: &PoolRole) -> bool {
+    e.storage().instance().has(&PoolDataKey::Role(*role))
+}
+
+/// Grant a role to an Address
+///
+/// ### Arguments
+/// * `role` - The role to grant
+/// * `address` - The Address granted the role
+pub fn set_role(e: &Env, role: &PoolRole, address: &Address) {
+    e.storage()
+        .instance()
+        .set::<PoolDataKey, Address>(&PoolDataKey::Role(*role), address);
+}
+
+/// Revoke a role, returning it to the admin
+///
+/// ### Arguments
+/// * `role` - The role to revoke
+pub fn del_role(e: &Env, role: &PoolRole) {
+    e.storage().instance().remove(&PoolDataKey::Role(*role));
+}
+
 /********** Metadata **********/
 
 /// Set a pool name
@@ -299,6 +532,150 @@ pub fn set_pool_config(e: &Env, config: &PoolConfig) {
         .set::<Symbol, PoolConfig>(&Symbol::new(e, POOL_CONFIG_KEY), config);
 }
 
+/// Fetch the queued pool config update
+///
+/// ### Panics
+/// If no pool config update is queued
+pub fn get_queued_pool_config(e: &Env) -> QueuedPoolConfig {
+    e.storage()
+        .temporary()
+        .get::<Symbol, QueuedPoolConfig>(&Symbol::new(e, QUEUED_POOL_CONFIG_KEY))
+        .unwrap_optimized()
+}
+
+/// Check if a pool config update is actively queued
+pub fn has_queued_pool_config(e: &Env) -> bool {
+    e.storage()
+        .temporary()
+        .has(&Symbol::new(e, QUEUED_POOL_CONFIG_KEY))
+}
+
+/// Set a new queued pool config update
+///
+/// ### Arguments
+/// * `queued_config` - The queued pool config update
+pub fn set_queued_pool_config(e: &Env, queued_config: &QueuedPoolConfig) {
+    let key = Symbol::new(e, QUEUED_POOL_CONFIG_KEY);
+    e.storage()
+        .temporary()
+        .set::<Symbol, QueuedPoolConfig>(&key, queued_config);
+    e.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Delete the queued pool config update
+pub fn del_queued_pool_config(e: &Env) {
+    e.storage()
+        .temporary()
+        .remove(&Symbol::new(e, QUEUED_POOL_CONFIG_KEY));
+}
+
+/********** Status Policy **********/
+
+/// Fetch the pool's status policy, or the default policy if one has not been set
+pub fn get_status_policy(e: &Env) -> StatusPolicy {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, STATUS_POLICY_KEY))
+        .unwrap_or(StatusPolicy {
+            on_ice_q4w: DEFAULT_ON_ICE_Q4W,
+            frozen_q4w: DEFAULT_FROZEN_Q4W,
+            admin_on_ice_q4w: DEFAULT_ADMIN_ON_ICE_Q4W,
+            admin_frozen_q4w: DEFAULT_ADMIN_FROZEN_Q4W,
+            min_threshold: DEFAULT_MIN_THRESHOLD,
+        })
+}
+
+/// Set the pool's status policy
+///
+/// ### Arguments
+/// * `policy` - The new status policy
+pub fn set_status_policy(e: &Env, policy: &StatusPolicy) {
+    e.storage()
+        .instance()
+        .set::<Symbol, StatusPolicy>(&Symbol::new(e, STATUS_POLICY_KEY), policy);
+}
+
+/// Fetch the queued status policy
+///
+/// ### Panics
+/// If a status policy has not been queued
+pub fn get_queued_status_policy(e: &Env) -> QueuedStatusPolicy {
+    e.storage()
+        .temporary()
+        .get::<Symbol, QueuedStatusPolicy>(&Symbol::new(e, QUEUED_STATUS_POLICY_KEY))
+        .unwrap_optimized()
+}
+
+/// Check if a status policy is actively queued
+pub fn has_queued_status_policy(e: &Env) -> bool {
+    e.storage()
+        .temporary()
+        .has(&Symbol::new(e, QUEUED_STATUS_POLICY_KEY))
+}
+
+/// Set a new queued status policy
+///
+/// ### Arguments
+/// * `queued_policy` - The queued status policy
+pub fn set_queued_status_policy(e: &Env, queued_policy: &QueuedStatusPolicy) {
+    let key = Symbol::new(e, QUEUED_STATUS_POLICY_KEY);
+    e.storage()
+        .temporary()
+        .set::<Symbol, QueuedStatusPolicy>(&key, queued_policy);
+    e.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Delete the queued status policy
+pub fn del_queued_status_policy(e: &Env) {
+    e.storage()
+        .temporary()
+        .remove(&Symbol::new(e, QUEUED_STATUS_POLICY_KEY));
+}
+
+/********** Bad Debt **********/
+
+/// Fetch the number of ledgers a bad debt auction can go unfilled before the backstop's
+/// bad debt can be written off
+pub fn get_write_off_delay(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, WRITE_OFF_DELAY_KEY))
+        .unwrap_or(DEFAULT_WRITE_OFF_DELAY)
+}
+
+/// Set the number of ledgers a bad debt auction can go unfilled before the backstop's
+/// bad debt can be written off
+///
+/// ### Arguments
+/// * `ledgers` - The write-off delay in ledgers
+pub fn set_write_off_delay(e: &Env, ledgers: &u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, WRITE_OFF_DELAY_KEY), ledgers);
+}
+
+/// Fetch the ledger sequence the bad debt auction was last filled on, or 0 if it never was
+pub fn get_bad_debt_last_fill(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, BAD_DEBT_FILL_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the ledger sequence the bad debt auction was last filled on
+///
+/// ### Arguments
+/// * `sequence` - The ledger sequence of the fill
+pub fn set_bad_debt_last_fill(e: &Env, sequence: &u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, BAD_DEBT_FILL_KEY), sequence);
+}
+
 /********** Reserve Config (ResConfig) **********/
 
 /// Fetch the reserve data for an asset
@@ -343,6 +720,36 @@ pub fn has_res(e: &Env, asset: &Address) -> bool {
     e.storage().persistent().has(&key)
 }
 
+/// Fetch the pause switches for a reserve, or no pauses if they have not been set
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_res_pause(e: &Env, asset: &Address) -> ReservePause {
+    let key = PoolDataKey::ResPause(asset.clone());
+    get_persistent_default(
+        e,
+        &key,
+        ReservePause::default,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the pause switches for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `pause` - The pause switches for the reserve
+pub fn set_res_pause(e: &Env, asset: &Address, pause: &ReservePause) {
+    let key = PoolDataKey::ResPause(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReservePause>(&key, pause);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /// Fetch a queued reserve set
 ///
 /// ### Arguments
@@ -394,6 +801,24 @@ pub fn del_queued_reserve_set(e: &Env, asset: &Address) {
     e.storage().temporary().remove(&key);
 }
 
+/// Fetch the list of new assets that have been queued to be set as a reserve
+pub fn get_queued_res_list(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Vec<Address>>(&Symbol::new(e, QUEUED_RES_LIST_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the list of new assets that have been queued to be set as a reserve
+///
+/// ### Arguments
+/// * `assets` - The contract addresses of the assets
+pub fn set_queued_res_list(e: &Env, assets: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, QUEUED_RES_LIST_KEY), assets);
+}
+
 /********** Reserve Data (ResData) **********/
 
 /// Fetch the reserve data for an asset
@@ -429,6 +854,40 @@ pub fn set_res_data(e: &Env, asset: &Address, data: &ReserveData) {
         .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/********** Reserve Wind-Down **********/
+
+/// Fetch the wind-downs of the pool's reserves, keyed by the reserve's underlying asset
+pub fn get_res_wind_downs(e: &Env) -> Map<Address, ReserveWindDown> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Map<Address, ReserveWindDown>>(&Symbol::new(e, RES_WIND_DOWN_KEY))
+        .unwrap_or(map![e])
+}
+
+/// Fetch the wind-down of a reserve, if it is being wound down
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_res_wind_down(e: &Env, asset: &Address) -> Option<ReserveWindDown> {
+    get_res_wind_downs(e).get(asset.clone())
+}
+
+/// Set the wind-down of a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `wind_down` - The wind-down of the reserve
+pub fn set_res_wind_down(e: &Env, asset: &Address, wind_down: &ReserveWindDown) {
+    let mut wind_downs = get_res_wind_downs(e);
+    wind_downs.set(asset.clone(), wind_down.clone());
+    e.storage()
+        .instance()
+        .set::<Symbol, Map<Address, ReserveWindDown>>(
+            &Symbol::new(e, RES_WIND_DOWN_KEY),
+            &wind_downs,
+        );
+}
+
 /********** Reserve List (ResList) **********/
 
 /// Fetch the list of reserves
@@ -450,7 +909,7 @@ pub fn get_res_list(e: &Env) -> Vec<Address> {
 /// ### Panics
 /// If the number of reserves in the list exceeds 50
 ///
-// @dev: Once added it can't be removed
+// @dev: Once added it can't be removed, and retired reserves keep their slot
 pub fn push_res_list(e: &Env, asset: &Address) -> u32 {
     let mut res_list = get_res_list(e);
     if res_list.len() >= MAX_RESERVES {
@@ -469,6 +928,49 @@ pub fn push_res_list(e: &Env, asset: &Address) -> u32 {
     new_index
 }
 
+/// Extend the TTL of the shared reserve entries without modifying them
+pub fn bump_reserves(e: &Env) {
+    let res_list = get_res_list(e);
+    extend_persistent_if_exists(
+        e,
+        &Symbol::new(e, POOL_EMIS_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+    extend_persistent_if_exists(
+        e,
+        &Symbol::new(e, POOL_EMIS_SCHEDULE_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+    for (index, asset) in res_list.iter().enumerate() {
+        let res_token_index = index as u32 * 2;
+        let keys = [
+            PoolDataKey::ResConfig(asset.clone()),
+            PoolDataKey::ResData(asset.clone()),
+            PoolDataKey::ResPause(asset.clone()),
+            PoolDataKey::EmisData(res_token_index),
+            PoolDataKey::EmisData(res_token_index + 1),
+        ];
+        for key in keys.iter() {
+            extend_persistent_if_exists(e, key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        }
+        for reserve_id in [res_token_index, res_token_index + 1] {
+            for reward_token in get_reward_tokens(e, &reserve_id).iter() {
+                extend_persistent_if_exists(
+                    e,
+                    &PoolDataKey::RewardData(RewardKey {
+                        reserve_id,
+                        reward_token,
+                    }),
+                    LEDGER_THRESHOLD_SHARED,
+                    LEDGER_BUMP_SHARED,
+                );
+            }
+        }
+    }
+}
+
 /********** Reserve Emissions **********/
 
 /// Fetch the emission data for the reserve b or d token
@@ -536,6 +1038,131 @@ pub fn set_user_emissions(e: &Env, user: &Address, res_token_index: &u32, data:
         .set::<PoolDataKey, UserEmissionData>(&key, data)
 }
 
+/********** Reserve Rewards **********/
+
+/// Fetch the reward tokens registered for a reserve b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+pub fn get_reward_tokens(e: &Env, res_token_index: &u32) -> Vec<Address> {
+    get_persistent_default(
+        e,
+        &PoolDataKey::RewardTkns(*res_token_index),
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the reward tokens registered for a reserve b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `reward_tokens` - The reward tokens for the reserve token
+pub fn set_reward_tokens(e: &Env, res_token_index: &u32, reward_tokens: &Vec<Address>) {
+    let key = PoolDataKey::RewardTkns(*res_token_index);
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, Vec<Address>>(&key, reward_tokens);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the reward data for a reserve b or d token and reward token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `reward_token` - The address of the reward token
+pub fn get_reward_data(
+    e: &Env,
+    res_token_index: &u32,
+    reward_token: &Address,
+) -> Option<ReserveEmissionData> {
+    let key = PoolDataKey::RewardData(RewardKey {
+        reserve_id: *res_token_index,
+        reward_token: reward_token.clone(),
+    });
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the reward data for a reserve b or d token and reward token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `reward_token` - The address of the reward token
+/// * `reward_data` - The new reward data
+pub fn set_reward_data(
+    e: &Env,
+    res_token_index: &u32,
+    reward_token: &Address,
+    reward_data: &ReserveEmissionData,
+) {
+    let key = PoolDataKey::RewardData(RewardKey {
+        reserve_id: *res_token_index,
+        reward_token: reward_token.clone(),
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReserveEmissionData>(&key, reward_data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the users reward data for a reserve's b or d token and reward token
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `reward_token` - The address of the reward token
+pub fn get_user_reward(
+    e: &Env,
+    user: &Address,
+    res_token_index: &u32,
+    reward_token: &Address,
+) -> Option<UserEmissionData> {
+    let key = PoolDataKey::UserReward(UserRewardKey {
+        user: user.clone(),
+        reserve_id: *res_token_index,
+        reward_token: reward_token.clone(),
+    });
+    get_persistent_default(e, &key, || None, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set the users reward data for a reserve's b or d token and reward token
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `reward_token` - The address of the reward token
+/// * `data` - The new user reward data
+pub fn set_user_reward(
+    e: &Env,
+    user: &Address,
+    res_token_index: &u32,
+    reward_token: &Address,
+    data: &UserEmissionData,
+) {
+    let key = PoolDataKey::UserReward(UserRewardKey {
+        user: user.clone(),
+        reserve_id: *res_token_index,
+        reward_token: reward_token.clone(),
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, UserEmissionData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
 /********** Pool Emissions **********/
 
 /// Fetch the pool reserve emissions
@@ -565,6 +1192,35 @@ pub fn set_pool_emissions(e: &Env, emissions: &Map<u32, u64>) {
     );
 }
 
+/// Fetch the pool reserve emission schedules
+pub fn get_pool_emis_schedules(e: &Env) -> Map<u32, ReserveEmissionSchedule> {
+    get_persistent_default(
+        e,
+        &Symbol::new(e, POOL_EMIS_SCHEDULE_KEY),
+        || map![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the pool reserve emission schedules
+///
+/// ### Arguments
+/// * `schedules` - The map of emission schedules by reserve token id
+pub fn set_pool_emis_schedules(e: &Env, schedules: &Map<u32, ReserveEmissionSchedule>) {
+    e.storage()
+        .persistent()
+        .set::<Symbol, Map<u32, ReserveEmissionSchedule>>(
+            &Symbol::new(e, POOL_EMIS_SCHEDULE_KEY),
+            schedules,
+        );
+    e.storage().persistent().extend_ttl(
+        &Symbol::new(e, POOL_EMIS_SCHEDULE_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+}
+
 /********** Auctions ***********/
 
 /// Fetch the auction data for an auction
@@ -134,6 +134,10 @@ pub(crate) fn create_backstop<'a>(
             usdc_token,
             pool_factory,
             vec![e, (pool_address.clone(), 40_000_000 * SCALAR_7)],
+            BytesN::from_array(e, &[0; 32]),
+            17 * 24 * 60 * 60_u64,
+            20_u32,
+            0_0500000_u32,
         ),
     );
     e.as_contract(pool_address, || {
@@ -1,9 +1,16 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, BytesN, Env, Vec};
 
 mod backstop_contract_wasm {
     soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/optimized/backstop.wasm");
 }
+
+mod backstop_share_token_wasm {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/optimized/backstop_share_token.wasm"
+    );
+}
 use backstop::{BackstopClient, BackstopContract};
+pub use backstop_share_token_wasm::WASM as BACKSTOP_SHARE_TOKEN_WASM;
 
 pub fn create_backstop<'a>(
     e: &Env,
@@ -15,6 +22,10 @@ pub fn create_backstop<'a>(
     usdc_token: &Address,
     pool_factory: &Address,
     drop_list: &Vec<(Address, i128)>,
+    share_token_hash: &BytesN<32>,
+    q4w_lock_time: u64,
+    max_q4w_size: u32,
+    instant_withdraw_fee: u32,
 ) -> BackstopClient<'a> {
     if wasm {
         e.register_at(
@@ -27,6 +38,10 @@ pub fn create_backstop<'a>(
                 usdc_token,
                 pool_factory,
                 drop_list.clone(),
+                share_token_hash,
+                q4w_lock_time,
+                max_q4w_size,
+                instant_withdraw_fee,
             ),
         );
     } else {
@@ -40,6 +55,10 @@ pub fn create_backstop<'a>(
                 usdc_token,
                 pool_factory,
                 drop_list.clone(),
+                share_token_hash,
+                q4w_lock_time,
+                max_q4w_size,
+                instant_withdraw_fee,
             ),
         );
     }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::ops::Index;
 
-use crate::backstop::create_backstop;
+use crate::backstop::{create_backstop, BACKSTOP_SHARE_TOKEN_WASM};
 use crate::emitter::create_emitter;
 use crate::liquidity_pool::{create_lp_pool, LPClient};
 use crate::oracle::create_mock_oracle;
@@ -99,6 +99,7 @@ impl TestFixture<'_> {
         blnd_client.set_admin(&emitter_id);
         emitter_client.initialize(&blnd_id, &backstop_id, &lp);
 
+        let share_token_hash = e.deployer().upload_contract_wasm(BACKSTOP_SHARE_TOKEN_WASM);
         let backstop_client = create_backstop(
             &e,
             &backstop_id,
@@ -113,6 +114,10 @@ impl TestFixture<'_> {
                 (bombadil.clone(), 10_000_000 * SCALAR_7),
                 (frodo.clone(), 30_000_000 * SCALAR_7)
             ],
+            &share_token_hash,
+            17 * 24 * 60 * 60,
+            20,
+            0_0500000,
         );
         let pool_hash = e.deployer().upload_contract_wasm(POOL_WASM);
         let pool_init_meta = PoolInitMeta {
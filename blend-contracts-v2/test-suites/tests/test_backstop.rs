@@ -1,10 +1,12 @@
 #![cfg(test)]
 
-use backstop::{BackstopClient, BackstopContract};
+use backstop::{BackstopClient, BackstopContract, BackstopDataKey, PoolUserKey};
+use pool::{Request, RequestType};
+use sep_41_token::TokenClient;
 use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{
     testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
-    vec, Address, Env, IntoVal, Symbol, Val, Vec,
+    vec, Address, BytesN, Env, Error, IntoVal, Symbol, Val, Vec,
 };
 use test_suites::{
     assertions::assert_approx_eq_abs,
@@ -511,6 +513,10 @@ fn test_backstop_constructor() {
         (Address::generate(&e), 10_000_000_0000000),
         (Address::generate(&e), 10_000_000_0000000),
     ];
+    let share_token_hash = BytesN::from_array(&e, &[1; 32]);
+    let q4w_lock_time: u64 = 7 * 24 * 60 * 60;
+    let max_q4w_size: u32 = 10;
+    let instant_withdraw_fee: u32 = 0_0200000;
     let contract_id = e.register(
         BackstopContract {},
         (
@@ -520,6 +526,10 @@ fn test_backstop_constructor() {
             usdc_token.clone(),
             pool_factory.clone(),
             drop_list.clone(),
+            share_token_hash.clone(),
+            q4w_lock_time,
+            max_q4w_size,
+            instant_withdraw_fee,
         ),
     );
 
@@ -558,6 +568,34 @@ fn test_backstop_constructor() {
             .get::<Symbol, Address>(&Symbol::new(&e, "PoolFact"))
             .unwrap();
         assert_eq!(contract_pool_factory, pool_factory);
+
+        let contract_share_token_hash = e
+            .storage()
+            .instance()
+            .get::<Symbol, BytesN<32>>(&Symbol::new(&e, "ShareHash"))
+            .unwrap();
+        assert_eq!(contract_share_token_hash, share_token_hash);
+
+        let contract_q4w_lock_time = e
+            .storage()
+            .instance()
+            .get::<Symbol, u64>(&Symbol::new(&e, "Q4WLock"))
+            .unwrap();
+        assert_eq!(contract_q4w_lock_time, q4w_lock_time);
+
+        let contract_max_q4w_size = e
+            .storage()
+            .instance()
+            .get::<Symbol, u32>(&Symbol::new(&e, "MaxQ4W"))
+            .unwrap();
+        assert_eq!(contract_max_q4w_size, max_q4w_size);
+
+        let contract_instant_withdraw_fee = e
+            .storage()
+            .instance()
+            .get::<Symbol, u32>(&Symbol::new(&e, "InstantFee"))
+            .unwrap();
+        assert_eq!(contract_instant_withdraw_fee, instant_withdraw_fee);
     });
 
     let backstop_client = BackstopClient::new(&e, &contract_id);
@@ -581,6 +619,10 @@ fn test_backstop_constructor_over_max() {
         (Address::generate(&e), 10_000_000_0000000),
         (Address::generate(&e), 10_000_000_0000001),
     ];
+    let share_token_hash = BytesN::from_array(&e, &[1; 32]);
+    let q4w_lock_time: u64 = 7 * 24 * 60 * 60;
+    let max_q4w_size: u32 = 10;
+    let instant_withdraw_fee: u32 = 0_0200000;
     e.register(
         BackstopContract {},
         (
@@ -590,6 +632,509 @@ fn test_backstop_constructor_over_max() {
             usdc_token.clone(),
             pool_factory.clone(),
             drop_list.clone(),
+            share_token_hash.clone(),
+            q4w_lock_time,
+            max_q4w_size,
+            instant_withdraw_fee,
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn test_backstop_constructor_q4w_lock_time_under_min() {
+    let e = Env::default();
+
+    let drop_list: Vec<(Address, i128)> = vec![&e, (Address::generate(&e), 10_000_000_0000000)];
+    e.register(
+        BackstopContract {},
+        (
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            drop_list,
+            BytesN::from_array(&e, &[1; 32]),
+            24 * 60 * 60 - 1_u64,
+            20_u32,
+            0_0500000_u32,
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn test_backstop_constructor_zero_max_q4w_size() {
+    let e = Env::default();
+
+    let drop_list: Vec<(Address, i128)> = vec![&e, (Address::generate(&e), 10_000_000_0000000)];
+    e.register(
+        BackstopContract {},
+        (
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            drop_list,
+            BytesN::from_array(&e, &[1; 32]),
+            17 * 24 * 60 * 60_u64,
+            0_u32,
+            0_0500000_u32,
         ),
     );
 }
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn test_backstop_constructor_instant_withdraw_fee_over_max() {
+    let e = Env::default();
+
+    let drop_list: Vec<(Address, i128)> = vec![&e, (Address::generate(&e), 10_000_000_0000000)];
+    e.register(
+        BackstopContract {},
+        (
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            drop_list,
+            BytesN::from_array(&e, &[1; 32]),
+            17 * 24 * 60 * 60_u64,
+            20_u32,
+            1_0000000_u32,
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn test_backstop_constructor_instant_withdraw_fee_under_min() {
+    let e = Env::default();
+
+    let drop_list: Vec<(Address, i128)> = vec![&e, (Address::generate(&e), 10_000_000_0000000)];
+    e.register(
+        BackstopContract {},
+        (
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+            drop_list,
+            BytesN::from_array(&e, &[1; 32]),
+            17 * 24 * 60 * 60_u64,
+            20_u32,
+            0_u32,
+        ),
+    );
+}
+
+#[test]
+fn test_backstop_bump_user_balance() {
+    let fixture = create_fixture_with_data(false);
+    let frodo = fixture.users.get(0).unwrap();
+    let pool = &fixture.pools[0].pool;
+
+    let user_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    assert!(user_balance.shares > 0);
+
+    // ~25 days pass without any interaction with the backstop
+    // @dev: reading the balance through the backstop extends its TTL, so only read the TTL
+    //       directly around the bump
+    fixture.jump_with_sequence(25 * 24 * 60 * 60);
+    let get_ttl = || {
+        fixture.env.as_contract(&fixture.backstop.address, || {
+            fixture
+                .env
+                .storage()
+                .persistent()
+                .get_ttl(&BackstopDataKey::UserBalance(PoolUserKey {
+                    pool: pool.address.clone(),
+                    user: frodo.clone(),
+                }))
+        })
+    };
+    let balance_ttl = get_ttl();
+    assert!(balance_ttl < 17280 * 100);
+
+    // bump user entries (permissionless)
+    fixture.backstop.bump_user_balance(&pool.address, &frodo);
+    assert_eq!(fixture.env.auths().len(), 0);
+    assert_eq!(get_ttl(), 17280 * 120);
+
+    // no state was modified
+    let new_user_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    assert_eq!(new_user_balance.shares, user_balance.shares);
+    assert_eq!(new_user_balance.q4w.len(), user_balance.q4w.len());
+}
+
+#[test]
+fn test_backstop_transfer_shares() {
+    let fixture = create_fixture_with_data(false);
+    let frodo = fixture.users.get(0).unwrap();
+    let pool = &fixture.pools[0].pool;
+    let sam = Address::generate(&fixture.env);
+
+    let frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    let to_transfer = frodo_balance.shares / 2;
+    fixture
+        .backstop
+        .transfer_shares(&frodo, &sam, &pool.address, &to_transfer);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    fixture.backstop.address.clone(),
+                    Symbol::new(&fixture.env, "transfer_shares"),
+                    vec![
+                        &fixture.env,
+                        frodo.to_val(),
+                        sam.to_val(),
+                        pool.address.to_val(),
+                        to_transfer.into_val(&fixture.env),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                fixture.backstop.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "transfer_shares"),
+                    pool.address.clone(),
+                    frodo.clone(),
+                    sam.clone()
+                )
+                    .into_val(&fixture.env),
+                to_transfer.into_val(&fixture.env)
+            )
+        ]
+    );
+    let new_frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    let sam_balance = fixture.backstop.user_balance(&pool.address, &sam);
+    assert_eq!(new_frodo_balance.shares, frodo_balance.shares - to_transfer);
+    assert_eq!(sam_balance.shares, to_transfer);
+
+    // emissions accrue to the new owner of the shares
+    fixture.jump_with_sequence(60 * 60 * 24);
+    let sam_claimed = fixture
+        .backstop
+        .claim(&sam, &vec![&fixture.env, pool.address.clone()], &sam);
+    assert!(sam_claimed > 0);
+    let frodo_claimed =
+        fixture
+            .backstop
+            .claim(&frodo, &vec![&fixture.env, pool.address.clone()], &frodo);
+    assert!(frodo_claimed > 0);
+}
+
+#[test]
+fn test_backstop_share_token() {
+    let fixture = create_fixture_with_data(false);
+    let frodo = fixture.users.get(0).unwrap();
+    let pool = &fixture.pools[0].pool;
+    let sam = Address::generate(&fixture.env);
+
+    let share_token = fixture.backstop.deploy_share_token(&pool.address);
+    assert_eq!(
+        fixture.backstop.share_token(&pool.address),
+        Some(share_token.clone())
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                fixture.backstop.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "deploy_share_token"),
+                    pool.address.clone(),
+                )
+                    .into_val(&fixture.env),
+                share_token.into_val(&fixture.env)
+            )
+        ]
+    );
+
+    // the share token wraps un-queued backstop shares
+    let share_token_client = TokenClient::new(&fixture.env, &share_token);
+    let frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    assert_eq!(share_token_client.balance(&frodo), frodo_balance.shares);
+    assert_eq!(share_token_client.decimals(), 7);
+
+    // queueing shares burns them
+    let to_queue = 100 * SCALAR_7;
+    fixture
+        .backstop
+        .queue_withdrawal(&frodo, &pool.address, &to_queue);
+    assert_eq!(
+        share_token_client.balance(&frodo),
+        frodo_balance.shares - to_queue
+    );
+
+    // transfers through the share token move backstop shares
+    let to_transfer = 1_000 * SCALAR_7;
+    share_token_client.transfer(&frodo, &sam, &to_transfer);
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                share_token.clone(),
+                (
+                    Symbol::new(&fixture.env, "transfer"),
+                    frodo.clone(),
+                    sam.clone()
+                )
+                    .into_val(&fixture.env),
+                to_transfer.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(share_token_client.balance(&sam), to_transfer);
+    assert_eq!(
+        fixture.backstop.user_balance(&pool.address, &sam).shares,
+        to_transfer
+    );
+    assert_eq!(
+        fixture.backstop.user_balance(&pool.address, &frodo).shares,
+        frodo_balance.shares - to_queue - to_transfer
+    );
+
+    // emissions follow the transferred shares
+    fixture.jump_with_sequence(60 * 60 * 24);
+    let sam_claimed = fixture
+        .backstop
+        .claim(&sam, &vec![&fixture.env, pool.address.clone()], &sam);
+    assert!(sam_claimed > 0);
+}
+
+#[test]
+fn test_backstop_emergency_withdraw() {
+    let fixture = create_fixture_with_data(false);
+    let frodo = fixture.users.get(0).unwrap();
+    let pool = &fixture.pools[0].pool;
+    let bstop_token = &fixture.lp;
+
+    let frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    let to_queue = frodo_balance.shares / 4;
+    fixture
+        .backstop
+        .queue_withdrawal(&frodo, &pool.address, &to_queue);
+    fixture.jump_with_sequence(60 * 60 * 24 * 2);
+    fixture
+        .backstop
+        .queue_withdrawal(&frodo, &pool.address, &to_queue);
+
+    // queued shares are locked while the pool is not admin frozen
+    let result = fixture
+        .backstop
+        .try_emergency_withdraw(&frodo, &pool.address);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(1012))));
+
+    pool.set_status(&4);
+
+    // queued shares are locked while the pool has outstanding borrows
+    let result = fixture
+        .backstop
+        .try_emergency_withdraw(&frodo, &pool.address);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(1012))));
+
+    // repay all borrows, refunding any excess
+    let requests: Vec<Request> = vec![
+        &fixture.env,
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::STABLE].address.clone(),
+            amount: 9_000 * 10i128.pow(6),
+        },
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::XLM].address.clone(),
+            amount: 70_000 * SCALAR_7,
+        },
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::WETH].address.clone(),
+            amount: 6 * 10i128.pow(9),
+        },
+    ];
+    pool.submit(&frodo, &frodo, &frodo, &requests);
+    assert!(pool.get_positions(&frodo).liabilities.is_empty());
+
+    let bstop_token_balance = bstop_token.balance(&frodo);
+    let pool_data = fixture.backstop.pool_data(&pool.address);
+    let amount = fixture.backstop.emergency_withdraw(&frodo, &pool.address);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    fixture.backstop.address.clone(),
+                    Symbol::new(&fixture.env, "emergency_withdraw"),
+                    vec![&fixture.env, frodo.to_val(), pool.address.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    let event_body: Vec<Val> = vec![
+        &fixture.env,
+        (to_queue * 2).into_val(&fixture.env),
+        amount.into_val(&fixture.env),
+    ];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                fixture.backstop.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "withdraw"),
+                    pool.address.clone(),
+                    frodo.clone()
+                )
+                    .into_val(&fixture.env),
+                event_body.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert!(amount > 0);
+    assert_eq!(bstop_token.balance(&frodo), bstop_token_balance + amount);
+    assert_eq!(
+        fixture.backstop.pool_data(&pool.address).tokens,
+        pool_data.tokens - amount
+    );
+    let new_frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    assert_eq!(
+        new_frodo_balance.shares,
+        frodo_balance.shares - to_queue * 2
+    );
+    assert_eq!(new_frodo_balance.q4w.len(), 0);
+}
+
+#[test]
+fn test_backstop_instant_withdraw() {
+    let fixture = create_fixture_with_data(false);
+    let frodo = fixture.users.get(0).unwrap();
+    let pool = &fixture.pools[0].pool;
+    let bstop_token = &fixture.lp;
+
+    // shares can't be instantly withdrawn while the pool has outstanding borrows
+    let result = fixture
+        .backstop
+        .try_instant_withdraw(&frodo, &pool.address, &(1_000 * SCALAR_7));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(1013))));
+
+    // repay all borrows, refunding any excess
+    let requests: Vec<Request> = vec![
+        &fixture.env,
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::STABLE].address.clone(),
+            amount: 9_000 * 10i128.pow(6),
+        },
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::XLM].address.clone(),
+            amount: 70_000 * SCALAR_7,
+        },
+        Request {
+            request_type: RequestType::Repay as u32,
+            address: fixture.tokens[TokenIndex::WETH].address.clone(),
+            amount: 6 * 10i128.pow(9),
+        },
+    ];
+    pool.submit(&frodo, &frodo, &frodo, &requests);
+    assert!(pool.get_positions(&frodo).liabilities.is_empty());
+
+    let frodo_balance = fixture.backstop.user_balance(&pool.address, &frodo);
+    let bstop_token_balance = bstop_token.balance(&frodo);
+    let pool_data = fixture.backstop.pool_data(&pool.address);
+    let amount = 1_000 * SCALAR_7;
+    let result = fixture
+        .backstop
+        .instant_withdraw(&frodo, &pool.address, &amount);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    fixture.backstop.address.clone(),
+                    Symbol::new(&fixture.env, "instant_withdraw"),
+                    vec![
+                        &fixture.env,
+                        frodo.to_val(),
+                        pool.address.to_val(),
+                        amount.into_val(&fixture.env),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    // frodo is the only depositor, so the shares are worth the pool's tokens
+    let tokens = amount
+        .fixed_mul_floor(pool_data.tokens, frodo_balance.shares)
+        .unwrap();
+    let fee = tokens.fixed_mul_ceil(0_0500000, SCALAR_7).unwrap();
+    assert_eq!(result, tokens - fee);
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    let event_body: Vec<Val> = vec![
+        &fixture.env,
+        amount.into_val(&fixture.env),
+        result.into_val(&fixture.env),
+        fee.into_val(&fixture.env),
+    ];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                fixture.backstop.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "instant_withdraw"),
+                    pool.address.clone(),
+                    frodo.clone()
+                )
+                    .into_val(&fixture.env),
+                event_body.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(bstop_token.balance(&frodo), bstop_token_balance + result);
+    // the fee stays in the pool's backstop
+    assert_eq!(
+        fixture.backstop.pool_data(&pool.address).tokens,
+        pool_data.tokens - result
+    );
+    assert_eq!(
+        fixture.backstop.user_balance(&pool.address, &frodo).shares,
+        frodo_balance.shares - amount
+    );
+
+    // instant withdrawals cannot push the pool's backstop over the max q4w percentage
+    fixture
+        .backstop
+        .queue_withdrawal(&frodo, &pool.address, &(12_000 * SCALAR_7));
+    let result = fixture
+        .backstop
+        .try_instant_withdraw(&frodo, &pool.address, &(10_000 * SCALAR_7));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(1013))));
+}
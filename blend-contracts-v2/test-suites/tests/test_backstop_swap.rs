@@ -15,7 +15,7 @@ use soroban_sdk::{
 };
 use test_suites::{
     assertions::assert_approx_eq_rel,
-    backstop::create_backstop,
+    backstop::{create_backstop, BACKSTOP_SHARE_TOKEN_WASM},
     liquidity_pool::LPClient,
     oracle::create_mock_oracle,
     pool::{default_reserve_metadata, POOL_WASM},
@@ -66,6 +66,9 @@ fn test_v1_to_v2_backstop_swap() {
         (samwise.clone(), 1_000_000 * 10i128.pow(7)),
         (v1_backstop.clone(), 1_000_000 * 10i128.pow(7)),
     ];
+    let share_token_hash = env
+        .deployer()
+        .upload_contract_wasm(BACKSTOP_SHARE_TOKEN_WASM);
     let v2_backstop_client = create_backstop(
         &env,
         &v2_backstop,
@@ -76,6 +79,10 @@ fn test_v1_to_v2_backstop_swap() {
         &usdc,
         &v2_pool_factory,
         &drop_list,
+        &share_token_hash,
+        17 * 24 * 60 * 60,
+        20,
+        0_0500000,
     );
 
     // Backstop_v1 balance of BLND_USDC_LP tokens
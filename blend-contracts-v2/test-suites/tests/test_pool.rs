@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use pool::{Request, RequestType, ReserveEmissionMetadata};
+use pool::{PoolDataKey, PoolRole, Request, RequestType, ReserveEmissionMetadata};
 use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{
     testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
@@ -565,11 +565,11 @@ fn test_pool_config() {
 
     let pool_fixture = &fixture.pools[0];
 
-    // Update pool config (admin only)
+    // Queue pool config update (admin only)
     let backstop_take_rate: u32 = 0_0500000;
     pool_fixture
         .pool
-        .update_pool(&backstop_take_rate, &6, &0_5000000);
+        .queue_update_pool(&backstop_take_rate, &6, &0_5000000);
     let event_data: soroban_sdk::Vec<Val> = vec![
         &fixture.env,
         backstop_take_rate.into_val(&fixture.env),
@@ -583,7 +583,7 @@ fn test_pool_config() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     pool_fixture.pool.address.clone(),
-                    Symbol::new(&fixture.env, "update_pool"),
+                    Symbol::new(&fixture.env, "queue_update_pool"),
                     event_data.into_val(&fixture.env)
                 )),
                 sub_invocations: std::vec![]
@@ -598,7 +598,7 @@ fn test_pool_config() {
             (
                 pool_fixture.pool.address.clone(),
                 (
-                    Symbol::new(&fixture.env, "update_pool"),
+                    Symbol::new(&fixture.env, "queue_update_pool"),
                     fixture.bombadil.clone()
                 )
                     .into_val(&fixture.env),
@@ -607,7 +607,28 @@ fn test_pool_config() {
         ]
     );
     let new_pool_config = fixture.read_pool_config(0);
+    assert_ne!(new_pool_config.bstop_rate, 0_0500000);
+    assert!(pool_fixture.pool.try_update_pool().is_err());
+
+    // Update pool config after the timelock
+    fixture.jump(604800); // 1 week
+    pool_fixture.pool.update_pool();
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (Symbol::new(&fixture.env, "update_pool"),).into_val(&fixture.env),
+                event_data.into_val(&fixture.env)
+            )
+        ]
+    );
+    let new_pool_config = fixture.read_pool_config(0);
     assert_eq!(new_pool_config.bstop_rate, 0_0500000);
+    assert_eq!(new_pool_config.max_positions, 6);
+    assert_eq!(new_pool_config.min_collateral, 0_5000000);
 
     // Initialize a reserve (admin only)
     let blnd = &fixture.tokens[TokenIndex::BLND];
@@ -661,7 +682,7 @@ fn test_pool_config() {
     assert_eq!(new_reserve_config.c_factor, 0_200_0000);
     assert_eq!(new_reserve_config.index, 3); // setup includes 3 assets (0 indexed)
 
-    // Update reserve config (admin only)
+    // Update reserve config (admin only) - lowering the c_factor applies immediately
     reserve_config.c_factor = 0;
     pool_fixture
         .pool
@@ -684,8 +705,6 @@ fn test_pool_config() {
             }
         )
     );
-    fixture.jump(604800); // 1 week
-    pool_fixture.pool.set_reserve(&blnd.address);
     assert_eq!(
         event,
         vec![
@@ -713,6 +732,8 @@ fn test_pool_config() {
     assert_eq!(new_reserve_config.l_factor, 0_500_0000);
     assert_eq!(new_reserve_config.c_factor, 0);
     assert_eq!(new_reserve_config.index, 3);
+    // nothing is left queued to set
+    assert!(pool_fixture.pool.try_set_reserve(&blnd.address).is_err());
 
     // Set admin (admin only)
     let new_admin = Address::generate(&fixture.env);
@@ -914,3 +935,416 @@ fn test_pool_config() {
     assert_eq!(new_emissions_config.get_unchecked(1 * 2 + 1), 0_400_0000);
     assert_eq!(new_emissions_config.get_unchecked(3 * 2 + 1), 0_200_0000);
 }
+
+/// Test the two-step admin transfer of the lending pool, including auth, events, and expiration.
+#[test]
+fn test_pool_admin_transfer() {
+    let fixture = create_fixture_with_data(false);
+
+    let pool_fixture = &fixture.pools[0];
+    let new_admin = Address::generate(&fixture.env);
+
+    // Propose admin (admin only)
+    let expiration = fixture.env.ledger().timestamp() + 24 * 60 * 60;
+    pool_fixture
+        .pool
+        .propose_admin(&new_admin, &Some(expiration));
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            fixture.bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "propose_admin"),
+                    vec![
+                        &fixture.env,
+                        new_admin.to_val(),
+                        Some(expiration).into_val(&fixture.env)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "propose_admin"),
+                    fixture.bombadil.clone()
+                )
+                    .into_val(&fixture.env),
+                (new_admin.clone(), Some(expiration)).into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(pool_fixture.pool.get_admin(), fixture.bombadil);
+    let pending_admin = pool_fixture.pool.get_pending_admin().unwrap();
+    assert_eq!(pending_admin.admin, new_admin);
+    assert_eq!(pending_admin.expiration, Some(expiration));
+
+    // Cancel the proposed admin (admin only)
+    pool_fixture.pool.cancel_propose_admin();
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "cancel_propose_admin"),
+                    fixture.bombadil.clone()
+                )
+                    .into_val(&fixture.env),
+                new_admin.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert!(pool_fixture.pool.get_pending_admin().is_none());
+    assert!(pool_fixture.pool.try_accept_admin().is_err());
+
+    // An expired proposal can't be accepted
+    pool_fixture
+        .pool
+        .propose_admin(&new_admin, &Some(expiration));
+    fixture.jump(24 * 60 * 60);
+    assert!(pool_fixture.pool.try_accept_admin().is_err());
+
+    // Accept admin (proposed admin only)
+    pool_fixture.pool.propose_admin(&new_admin, &None);
+    pool_fixture.pool.accept_admin();
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            new_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "accept_admin"),
+                    vec![&fixture.env]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "accept_admin"),
+                    fixture.bombadil.clone()
+                )
+                    .into_val(&fixture.env),
+                new_admin.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(pool_fixture.pool.get_admin(), new_admin);
+    assert!(pool_fixture.pool.get_pending_admin().is_none());
+}
+
+#[test]
+fn test_pool_roles() {
+    let fixture = create_fixture_with_data(false);
+
+    let pool_fixture = &fixture.pools[0];
+    let risk_manager = Address::generate(&fixture.env);
+    let emissions_manager = Address::generate(&fixture.env);
+    let guardian = Address::generate(&fixture.env);
+
+    // Roles default to the admin
+    assert_eq!(
+        pool_fixture.pool.get_role(&PoolRole::RiskManager),
+        fixture.bombadil
+    );
+    assert!(pool_fixture
+        .pool
+        .try_revoke_role(&PoolRole::RiskManager)
+        .is_err());
+
+    // Grant role (admin only)
+    pool_fixture
+        .pool
+        .grant_role(&PoolRole::RiskManager, &risk_manager);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            fixture.bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "grant_role"),
+                    vec![
+                        &fixture.env,
+                        PoolRole::RiskManager.into_val(&fixture.env),
+                        risk_manager.to_val()
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "grant_role"),
+                    fixture.bombadil.clone(),
+                    PoolRole::RiskManager
+                )
+                    .into_val(&fixture.env),
+                risk_manager.into_val(&fixture.env)
+            )
+        ]
+    );
+    pool_fixture
+        .pool
+        .grant_role(&PoolRole::EmissionsManager, &emissions_manager);
+    pool_fixture.pool.grant_role(&PoolRole::Guardian, &guardian);
+    assert_eq!(
+        pool_fixture.pool.get_role(&PoolRole::RiskManager),
+        risk_manager
+    );
+    assert_eq!(
+        pool_fixture.pool.get_role(&PoolRole::EmissionsManager),
+        emissions_manager
+    );
+    assert_eq!(pool_fixture.pool.get_role(&PoolRole::Guardian), guardian);
+
+    // Queue pool update (risk manager only)
+    pool_fixture
+        .pool
+        .queue_update_pool(&0_1000000, &8, &1_0000000);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            risk_manager.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "queue_update_pool"),
+                    vec![
+                        &fixture.env,
+                        0_1000000u32.into_val(&fixture.env),
+                        8u32.into_val(&fixture.env),
+                        1_0000000i128.into_val(&fixture.env)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // Set emissions config (emissions manager only)
+    let reserve_emissions = vec![
+        &fixture.env,
+        ReserveEmissionMetadata {
+            res_index: 0,
+            res_type: 1,
+            share: 1_0000000,
+        },
+    ];
+    pool_fixture.pool.set_emissions_config(&reserve_emissions);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            emissions_manager.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "set_emissions_config"),
+                    vec![&fixture.env, reserve_emissions.to_val()]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // Freeze the pool (guardian only)
+    pool_fixture.pool.freeze();
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            guardian.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "freeze"),
+                    vec![&fixture.env]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (Symbol::new(&fixture.env, "set_status"), guardian.clone()).into_val(&fixture.env),
+                4u32.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(pool_fixture.pool.get_config().status, 4);
+
+    // Set status (admin only)
+    pool_fixture.pool.set_status(&2);
+    assert_eq!(
+        fixture.env.auths()[0],
+        (
+            fixture.bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool_fixture.pool.address.clone(),
+                    Symbol::new(&fixture.env, "set_status"),
+                    vec![&fixture.env, 2u32.into_val(&fixture.env)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(pool_fixture.pool.get_config().status, 2);
+
+    // Revoke role (admin only)
+    pool_fixture.pool.revoke_role(&PoolRole::RiskManager);
+    let event = vec![&fixture.env, fixture.env.events().all().last_unchecked()];
+    assert_eq!(
+        event,
+        vec![
+            &fixture.env,
+            (
+                pool_fixture.pool.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "revoke_role"),
+                    fixture.bombadil.clone(),
+                    PoolRole::RiskManager
+                )
+                    .into_val(&fixture.env),
+                risk_manager.into_val(&fixture.env)
+            )
+        ]
+    );
+    assert_eq!(
+        pool_fixture.pool.get_role(&PoolRole::RiskManager),
+        fixture.bombadil
+    );
+}
+
+#[test]
+fn test_pool_bump_ttl() {
+    let fixture = create_fixture_with_data(false);
+    let pool_fixture = &fixture.pools[0];
+    let pool_address = pool_fixture.pool.address.clone();
+    let frodo = fixture.users[0].clone();
+    let xlm_address = fixture.tokens[TokenIndex::XLM].address.clone();
+
+    let positions = pool_fixture.pool.get_positions(&frodo);
+    let reserve = pool_fixture.pool.get_reserve(&xlm_address);
+
+    // ~25 days pass without any interaction with the pool
+    // @dev: reading the entries through the pool extends their TTL, so only read the TTL
+    //       directly between the bumps
+    fixture.jump_with_sequence(25 * 24 * 60 * 60);
+    let get_ttls = || {
+        fixture.env.as_contract(&pool_address, || {
+            let storage = fixture.env.storage().persistent();
+            (
+                storage.get_ttl(&PoolDataKey::Positions(frodo.clone())),
+                storage.get_ttl(&PoolDataKey::ResConfig(xlm_address.clone())),
+                storage.get_ttl(&PoolDataKey::ResData(xlm_address.clone())),
+            )
+        })
+    };
+    let (positions_ttl, config_ttl, data_ttl) = get_ttls();
+    assert!(positions_ttl < 17280 * 100);
+    assert!(config_ttl < 17280 * 45);
+    assert!(data_ttl < 17280 * 45);
+
+    // bump user entries (permissionless)
+    pool_fixture.pool.bump_user(&frodo);
+    assert_eq!(fixture.env.auths().len(), 0);
+    let (positions_ttl, new_config_ttl, new_data_ttl) = get_ttls();
+    assert_eq!(positions_ttl, 17280 * 120);
+    assert_eq!(new_config_ttl, config_ttl);
+    assert_eq!(new_data_ttl, data_ttl);
+
+    // bump reserve entries (permissionless)
+    pool_fixture.pool.bump_reserves();
+    assert_eq!(fixture.env.auths().len(), 0);
+    let (_, config_ttl, data_ttl) = get_ttls();
+    assert_eq!(config_ttl, 17280 * 46);
+    assert_eq!(data_ttl, 17280 * 46);
+
+    // no state was modified
+    let new_positions = pool_fixture.pool.get_positions(&frodo);
+    assert_eq!(new_positions.collateral, positions.collateral);
+    assert_eq!(new_positions.liabilities, positions.liabilities);
+    assert_eq!(new_positions.supply, positions.supply);
+    let new_reserve = pool_fixture.pool.get_reserve(&xlm_address);
+    assert_eq!(new_reserve.data.b_rate, reserve.data.b_rate);
+    assert_eq!(new_reserve.data.d_rate, reserve.data.d_rate);
+    assert_eq!(new_reserve.data.last_time, reserve.data.last_time);
+}
+
+#[test]
+fn test_pool_claim_to_backstop() {
+    let fixture = create_fixture_with_data(false);
+    let pool_fixture = &fixture.pools[0];
+    let frodo = fixture.users[0].clone();
+    let blnd = &fixture.tokens[TokenIndex::BLND];
+
+    fixture.jump(60 * 60 * 24);
+
+    let frodo_blnd_balance = blnd.balance(&frodo);
+    let frodo_lp_balance = fixture.lp.balance(&frodo);
+    let backstop_blnd_balance = blnd.balance(&fixture.backstop.address);
+    let user_balance = fixture
+        .backstop
+        .user_balance(&pool_fixture.pool.address, &frodo);
+
+    // slippage protection
+    assert!(pool_fixture
+        .pool
+        .try_claim_to_backstop(
+            &frodo,
+            &vec![&fixture.env, 0, 3],
+            &pool_fixture.pool.address,
+            &(1_000_000 * SCALAR_7),
+        )
+        .is_err());
+
+    let shares = pool_fixture.pool.claim_to_backstop(
+        &frodo,
+        &vec![&fixture.env, 0, 3],
+        &pool_fixture.pool.address,
+        &0,
+    );
+    assert!(shares > 0);
+
+    // claimed BLND is moved from the backstop into the backstop token, and frodo's
+    // share of the backstop increases
+    assert!(blnd.balance(&fixture.backstop.address) < backstop_blnd_balance);
+    assert_eq!(blnd.balance(&frodo), frodo_blnd_balance);
+    assert_eq!(fixture.lp.balance(&frodo), frodo_lp_balance);
+    let new_user_balance = fixture
+        .backstop
+        .user_balance(&pool_fixture.pool.address, &frodo);
+    assert_eq!(new_user_balance.shares, user_balance.shares + shares);
+}
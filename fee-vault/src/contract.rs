@@ -175,6 +175,79 @@ impl FeeVault {
         storage::get_admin(&e).require_auth();
         admin.require_auth();
         storage::set_admin(&e, admin);
+        storage::del_pending_admin(&e);
+    }
+
+    /// ADMIN ONLY
+    /// Proposes a new admin address for the fee vault. The proposed admin must call
+    /// `accept_admin` to take over the admin role.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The proposed admin address
+    /// * `expiration` - The timestamp the proposal expires at, or None if it does not expire
+    ///
+    /// ### Panics
+    /// * `PendingAdminExpired` - If the expiration has already passed
+    pub fn propose_admin(e: Env, new_admin: Address, expiration: Option<u64>) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+        if let Some(expiration) = expiration {
+            if expiration <= e.ledger().timestamp() {
+                panic_with_error!(&e, FeeVaultError::PendingAdminExpired);
+            }
+        }
+
+        storage::set_pending_admin(
+            &e,
+            storage::PendingAdmin {
+                admin: new_admin.clone(),
+                expiration,
+            },
+        );
+
+        FeeVaultEvents::propose_admin(&e, &admin, &new_admin, expiration);
+    }
+
+    /// ADMIN ONLY
+    /// Cancels the proposed admin for the fee vault
+    ///
+    /// ### Panics
+    /// * `NoPendingAdmin` - If no admin has been proposed
+    pub fn cancel_propose_admin(e: Env) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+        let pending_admin = storage::get_pending_admin(&e)
+            .unwrap_or_else(|| panic_with_error!(&e, FeeVaultError::NoPendingAdmin));
+
+        storage::del_pending_admin(&e);
+
+        FeeVaultEvents::cancel_propose_admin(&e, &admin, &pending_admin.admin);
+    }
+
+    /// PROPOSED ADMIN ONLY
+    /// Accepts the admin role for the fee vault
+    ///
+    /// ### Panics
+    /// * `NoPendingAdmin` - If no admin has been proposed
+    /// * `PendingAdminExpired` - If the proposal has expired
+    pub fn accept_admin(e: Env) {
+        storage::extend_instance(&e);
+        let pending_admin = storage::get_pending_admin(&e)
+            .unwrap_or_else(|| panic_with_error!(&e, FeeVaultError::NoPendingAdmin));
+        pending_admin.admin.require_auth();
+        if let Some(expiration) = pending_admin.expiration {
+            if expiration <= e.ledger().timestamp() {
+                panic_with_error!(&e, FeeVaultError::PendingAdminExpired);
+            }
+        }
+
+        let admin = storage::get_admin(&e);
+        storage::set_admin(&e, pending_admin.admin.clone());
+        storage::del_pending_admin(&e);
+
+        FeeVaultEvents::accept_admin(&e, &admin, &pending_admin.admin);
     }
 
     /// ADMIN ONLY
@@ -16,4 +16,6 @@ pub enum FeeVaultError {
     InvalidBTokensMinted = 106,
     InvalidBTokensBurnt = 107,
     InvalidSharesMinted = 108,
+    NoPendingAdmin = 109,
+    PendingAdminExpired = 110,
 }
@@ -94,4 +94,31 @@ impl FeeVaultEvents {
 
         e.events().publish(topics, (is_apr_capped, value));
     }
+
+    /// Emitted when a new admin is proposed for a fee vault
+    ///
+    /// - topics - `["propose_admin", admin: Address]`
+    /// - data - `[new_admin: Address, expiration: Option<u64>]`
+    pub fn propose_admin(e: &Env, admin: &Address, new_admin: &Address, expiration: Option<u64>) {
+        let topics = (Symbol::new(&e, "propose_admin"), admin.clone());
+        e.events().publish(topics, (new_admin.clone(), expiration));
+    }
+
+    /// Emitted when a proposed admin is cancelled for a fee vault
+    ///
+    /// - topics - `["cancel_propose_admin", admin: Address]`
+    /// - data - `new_admin: Address`
+    pub fn cancel_propose_admin(e: &Env, admin: &Address, new_admin: &Address) {
+        let topics = (Symbol::new(&e, "cancel_propose_admin"), admin.clone());
+        e.events().publish(topics, new_admin.clone());
+    }
+
+    /// Emitted when a proposed admin accepts the admin role of a fee vault
+    ///
+    /// - topics - `["accept_admin", admin: Address]`
+    /// - data - `new_admin: Address`
+    pub fn accept_admin(e: &Env, admin: &Address, new_admin: &Address) {
+        let topics = (Symbol::new(&e, "accept_admin"), admin.clone());
+        e.events().publish(topics, new_admin.clone());
+    }
 }
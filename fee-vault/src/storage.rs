@@ -10,6 +10,7 @@ const POOL_KEY: &str = "Pool";
 const ADMIN_KEY: &str = "Admin";
 const FEE_MODE_KEY: &str = "FeeModeKey";
 const RESERVES_KEY: &str = "Reserves";
+const PENDING_ADMIN_KEY: &str = "PendingAdmin";
 
 #[derive(Clone)]
 #[contracttype]
@@ -32,6 +33,13 @@ pub struct FeeMode {
     pub value: i128,         // the apr_cap value if is_apr_capped, otherwise the admin's take_rate
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub admin: Address,          // the proposed admin
+    pub expiration: Option<u64>, // the timestamp the proposal expires at, if any
+}
+
 //********** Storage Utils **********//
 
 pub const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5 seconds per ledger on average
@@ -81,6 +89,27 @@ pub fn set_admin(e: &Env, admin: Address) {
         .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), &admin);
 }
 
+/// Get the pending admin, if one has been proposed
+pub fn get_pending_admin(e: &Env) -> Option<PendingAdmin> {
+    e.storage()
+        .instance()
+        .get::<Symbol, PendingAdmin>(&Symbol::new(e, PENDING_ADMIN_KEY))
+}
+
+/// Set the pending admin
+pub fn set_pending_admin(e: &Env, pending_admin: PendingAdmin) {
+    e.storage()
+        .instance()
+        .set::<Symbol, PendingAdmin>(&Symbol::new(e, PENDING_ADMIN_KEY), &pending_admin);
+}
+
+/// Remove the pending admin
+pub fn del_pending_admin(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, PENDING_ADMIN_KEY));
+}
+
 /// Get the fee mode for the fee vault
 pub fn get_fee_mode(e: &Env) -> FeeMode {
     e.storage()
@@ -554,6 +554,99 @@ fn test_set_admin() {
     );
 }
 
+#[test]
+fn test_propose_and_accept_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.set_default_info();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let vault_address = register_fee_vault(
+        &e,
+        Some((samwise.clone(), Address::generate(&e), true, 0_1000000)),
+    );
+    let vault_client = FeeVaultClient::new(&e, &vault_address);
+
+    // nothing to accept or cancel before a proposal
+    assert_eq!(
+        vault_client.try_accept_admin().err(),
+        Some(Ok(Error::from_contract_error(109)))
+    );
+    assert_eq!(
+        vault_client.try_cancel_propose_admin().err(),
+        Some(Ok(Error::from_contract_error(109)))
+    );
+
+    // an expiration in the past is rejected
+    assert_eq!(
+        vault_client
+            .try_propose_admin(&frodo, &Some(e.ledger().timestamp()))
+            .err(),
+        Some(Ok(Error::from_contract_error(110)))
+    );
+
+    let expiration = e.ledger().timestamp() + 1000;
+    vault_client.propose_admin(&frodo, &Some(expiration));
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    vault_address.clone(),
+                    Symbol::new(&e, "propose_admin"),
+                    vec![&e, frodo.into_val(&e), Some(expiration).into_val(&e)],
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    e.as_contract(&vault_address, || {
+        // the proposal does not change the admin
+        assert_eq!(storage::get_admin(&e), samwise.clone());
+        let pending_admin = storage::get_pending_admin(&e).unwrap();
+        assert_eq!(pending_admin.admin, frodo.clone());
+        assert_eq!(pending_admin.expiration, Some(expiration));
+    });
+
+    // the proposal can be cancelled
+    vault_client.cancel_propose_admin();
+    e.as_contract(&vault_address, || {
+        assert!(storage::get_pending_admin(&e).is_none());
+    });
+
+    // an expired proposal can't be accepted
+    vault_client.propose_admin(&frodo, &Some(expiration));
+    e.jump_time(1000);
+    assert_eq!(
+        vault_client.try_accept_admin().err(),
+        Some(Ok(Error::from_contract_error(110)))
+    );
+
+    vault_client.propose_admin(&frodo, &None);
+    vault_client.accept_admin();
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    vault_address.clone(),
+                    Symbol::new(&e, "accept_admin"),
+                    vec![&e],
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    e.as_contract(&vault_address, || {
+        assert_eq!(storage::get_admin(&e), frodo);
+        assert!(storage::get_pending_admin(&e).is_none());
+    });
+}
+
 #[test]
 fn test_add_reserve_vault() {
     let e = Env::default();